@@ -0,0 +1,180 @@
+use std::ops::Range;
+use std::path::Path;
+
+use zed_openspec::spec::paths::{self, normalize_name, SpecLocation};
+use zed_openspec::spec::{parse_spec, DeltaKind};
+
+/// Quick fix for a finding, as byte-range edits to the document
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fix {
+    pub title: String,
+    pub edits: Vec<(Range<usize>, String)>,
+}
+
+/// Quick fix for a finding of `rule_id` covering `range` in `text`
+///
+/// `file` is the workspace-relative path; `root` is used to look up the
+/// source spec when wrapping requirements in delta sections.
+pub fn quick_fix(rule_id: &str, file: &str, text: &str, range: Range<usize>, root: Option<&Path>) -> Option<Fix> {
+    match rule_id {
+        "OS001" => add_scenario(text, range.start),
+        "OS005" | "OS006" => upgrade_should(text, range),
+        "OS015" => fix_heading_level(text, range.start),
+        "OS016" => wrap_in_deltas(file, text, root),
+        _ => None,
+    }
+}
+
+/// Append a WHEN/THEN scenario skeleton to the requirement at `offset`
+fn add_scenario(text: &str, offset: usize) -> Option<Fix> {
+    let doc = parse_spec(text);
+    let requirement = doc.all_requirements().find(|r| r.heading.span.contains(offset))?;
+    let skeleton = format!(
+        "\n\n{} Scenario: {}\n- **WHEN** <condition>\n- **THEN** <expected outcome>",
+        "#".repeat(requirement.heading.level + 1),
+        requirement.name
+    );
+    let end = requirement.span.end;
+    Some(Fix {
+        title: "Add scenario skeleton".to_string(),
+        edits: vec![(end..end, skeleton)],
+    })
+}
+
+/// Replace the first standalone "should" in `range` with "SHALL"
+fn upgrade_should(text: &str, range: Range<usize>) -> Option<Fix> {
+    let sentence = text.get(range.clone())?;
+    let lower = sentence.to_ascii_lowercase();
+    let is_word_char = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric());
+
+    let start = lower.match_indices("should").map(|(i, _)| i).find(|&i| {
+        !is_word_char(sentence[..i].chars().next_back()) && !is_word_char(sentence[i + 6..].chars().next())
+    })?;
+    let start = range.start + start;
+    Some(Fix {
+        title: "Replace 'should' with 'SHALL'".to_string(),
+        edits: vec![(start..start + 6, "SHALL".to_string())],
+    })
+}
+
+/// Change the `#` markers of the heading at `offset` to the expected level
+fn fix_heading_level(text: &str, offset: usize) -> Option<Fix> {
+    let doc = parse_spec(text);
+    let heading = doc.headings.iter().find(|h| h.span.contains(offset))?;
+    let expected = heading.expected_level()?;
+
+    let line = &text[heading.span.start..heading.span.end];
+    let markers = heading.span.start + (line.len() - line.trim_start().len());
+    let hashes = "#".repeat(expected);
+    Some(Fix {
+        title: format!("Change heading to {}", hashes),
+        edits: vec![(markers..markers + heading.level, hashes)],
+    })
+}
+
+/// Put requirements that sit outside any delta section under one
+///
+/// Requirements already in the source spec go under MODIFIED, the rest
+/// under ADDED. Adjacent requirements of the same kind share a section.
+fn wrap_in_deltas(file: &str, text: &str, root: Option<&Path>) -> Option<Fix> {
+    let SpecLocation::Delta { capability, .. } = paths::classify(file) else {
+        return None;
+    };
+    let existing: Vec<String> = root
+        .and_then(|root| std::fs::read_to_string(root.join(paths::source_spec_path(capability))).ok())
+        .map(|source| parse_spec(&source).requirements.iter().map(|r| normalize_name(&r.name)).collect())
+        .unwrap_or_default();
+
+    let doc = parse_spec(text);
+    let mut edits = Vec::new();
+    let mut previous: Option<(usize, DeltaKind)> = None;
+    for requirement in &doc.requirements {
+        let kind = if existing.contains(&normalize_name(&requirement.name)) {
+            DeltaKind::Modified
+        } else {
+            DeltaKind::Added
+        };
+        let start = requirement.heading.span.start;
+        let continues = previous.is_some_and(|(end, previous_kind)| {
+            previous_kind == kind && text[end..start].trim().is_empty()
+        });
+        if !continues {
+            edits.push((start..start, format!("## {} Requirements\n\n", kind.as_str())));
+        }
+        previous = Some((requirement.span.end, kind));
+    }
+    if edits.is_empty() {
+        return None;
+    }
+
+    let title = if edits.iter().all(|(_, heading)| heading == &edits[0].1) {
+        format!("Wrap in {} section", edits[0].1.trim_start_matches("## ").trim_end())
+    } else {
+        "Wrap in ADDED and MODIFIED Requirements sections".to_string()
+    };
+    Some(Fix { title, edits })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn apply(text: &str, fix: &Fix) -> String {
+        let mut out = text.to_string();
+        for (range, new_text) in fix.edits.iter().rev() {
+            out.replace_range(range.clone(), new_text);
+        }
+        out
+    }
+
+    #[test]
+    fn test_spec_fixes() {
+        let spec = "### Requirement: Export\nThe exporter should write CSV.\n\n## Notes\n";
+        let file = "openspec/specs/export/spec.md";
+
+        let fix = quick_fix("OS001", file, spec, 0..23, None).unwrap();
+        assert_eq!(
+            apply(spec, &fix),
+            "### Requirement: Export\nThe exporter should write CSV.\n\n\
+             #### Scenario: Export\n- **WHEN** <condition>\n- **THEN** <expected outcome>\n\n## Notes\n"
+        );
+
+        let sentence = spec.find("The").unwrap()..spec.find("CSV.").unwrap() + 4;
+        let fix = quick_fix("OS006", file, spec, sentence, None).unwrap();
+        assert!(apply(spec, &fix).contains("The exporter SHALL write CSV."));
+        assert!(quick_fix("OS006", file, "Users shoulder it.", 0..18, None).is_none());
+
+        let spec = "### Requirement: Export\nThe system SHALL export.\n\n## Scenario: Done\n";
+        let heading = spec.find("## Scenario").unwrap();
+        let fix = quick_fix("OS015", file, spec, heading..heading + 17, None).unwrap();
+        assert_eq!(fix.title, "Change heading to ####");
+        assert!(apply(spec, &fix).ends_with("\n#### Scenario: Done\n"));
+    }
+
+    #[test]
+    fn test_wrap_requirements_in_deltas() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source = temp_dir.path().join("openspec/specs/auth/spec.md");
+        fs::create_dir_all(source.parent().unwrap()).unwrap();
+        fs::write(&source, "### Requirement: Login\nThe system SHALL log users in.\n").unwrap();
+
+        let change = "### Requirement: Login\nThe system SHALL ask for a code.\n\n\
+                      ### Requirement: Recovery\nThe system SHALL send codes.\n\n\
+                      ### Requirement: Backup Codes\nThe system SHALL issue backup codes.\n";
+        let file = "openspec/changes/add-2fa/specs/auth/spec.md";
+        let fix = quick_fix("OS016", file, change, 0..22, Some(temp_dir.path())).unwrap();
+
+        assert_eq!(fix.title, "Wrap in ADDED and MODIFIED Requirements sections");
+        assert_eq!(
+            apply(change, &fix),
+            "## MODIFIED Requirements\n\n### Requirement: Login\nThe system SHALL ask for a code.\n\n\
+             ## ADDED Requirements\n\n### Requirement: Recovery\nThe system SHALL send codes.\n\n\
+             ### Requirement: Backup Codes\nThe system SHALL issue backup codes.\n"
+        );
+
+        let fix = quick_fix("OS016", file, change, 0..22, None).unwrap();
+        assert_eq!(fix.title, "Wrap in ADDED Requirements section");
+        assert_eq!(fix.edits.len(), 1);
+    }
+}
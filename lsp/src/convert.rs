@@ -0,0 +1,76 @@
+use tower_lsp::lsp_types::{self, DiagnosticSeverity, NumberOrString};
+use zed_openspec::spec::{LineIndex, Span};
+use zed_openspec::validation::{Diagnostic, Severity};
+
+/// Convert a byte offset into an LSP position (UTF-16 columns)
+pub fn to_position(text: &str, index: &LineIndex, offset: usize) -> lsp_types::Position {
+    let position = index.position(offset);
+    let line_start = index.line_start(position.line).unwrap_or(0);
+    let offset = offset.min(text.len());
+    lsp_types::Position {
+        line: position.line as u32,
+        character: text[line_start..offset].encode_utf16().count() as u32,
+    }
+}
+
+/// Convert an LSP position (UTF-16 columns) into a byte offset, clamped to the line
+pub fn to_offset(text: &str, index: &LineIndex, position: lsp_types::Position) -> usize {
+    let Some(line_start) = index.line_start(position.line as usize) else {
+        return text.len();
+    };
+    let line = text[line_start..].split('\n').next().unwrap_or("");
+    let mut units = 0;
+    for (i, c) in line.char_indices() {
+        if units >= position.character as usize {
+            return line_start + i;
+        }
+        units += c.len_utf16();
+    }
+    line_start + line.len()
+}
+
+pub fn to_range(text: &str, index: &LineIndex, span: &Span) -> lsp_types::Range {
+    lsp_types::Range {
+        start: to_position(text, index, span.start),
+        end: to_position(text, index, span.end),
+    }
+}
+
+pub fn to_severity(severity: Severity) -> DiagnosticSeverity {
+    match severity {
+        Severity::Error => DiagnosticSeverity::ERROR,
+        Severity::Warning => DiagnosticSeverity::WARNING,
+        Severity::Information => DiagnosticSeverity::INFORMATION,
+        Severity::Hint => DiagnosticSeverity::HINT,
+    }
+}
+
+pub fn to_diagnostic(text: &str, index: &LineIndex, diagnostic: &Diagnostic) -> lsp_types::Diagnostic {
+    lsp_types::Diagnostic {
+        range: to_range(text, index, &diagnostic.span),
+        severity: Some(to_severity(diagnostic.severity)),
+        code: Some(NumberOrString::String(diagnostic.rule_id.clone())),
+        source: Some("openspec".to_string()),
+        message: format!("{} ({})", diagnostic.message, diagnostic.rule_name),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions_use_utf16_columns() {
+        let text = "# 😀 Title\n### Requirement: Login";
+        let index = LineIndex::new(text);
+
+        let title_end = text.find('\n').unwrap();
+        assert_eq!(to_position(text, &index, title_end), lsp_types::Position::new(0, 10));
+
+        let login = text.find("Login").unwrap();
+        assert_eq!(to_position(text, &index, login), lsp_types::Position::new(1, 17));
+        assert_eq!(to_offset(text, &index, lsp_types::Position::new(1, 17)), login);
+        assert_eq!(to_offset(text, &index, lsp_types::Position::new(0, 99)), title_end);
+    }
+}
@@ -0,0 +1,170 @@
+use std::path::Path;
+
+use zed_openspec::spec::paths::{self, SpecLocation};
+use zed_openspec::spec::tasks::{parse_tasks, Task};
+use zed_openspec::spec::workspace::{requirement_changes, RequirementChange};
+use zed_openspec::spec::{parse_spec, Requirement, Span};
+use zed_openspec::utils::audit::{load_audit_records, AuditRecord};
+use zed_openspec::validation::is_tasks_file;
+
+/// Markdown hover for the requirement heading or task under `offset`,
+/// with the span it applies to
+///
+/// `file` is the workspace-relative path. Without a workspace root only
+/// what is in the document itself is shown.
+pub fn hover_markdown(file: &str, text: &str, offset: usize, root: Option<&Path>) -> Option<(String, Span)> {
+    match paths::classify(file) {
+        SpecLocation::Source { capability } | SpecLocation::Delta { capability, .. } => {
+            let doc = parse_spec(text);
+            let requirement = doc.all_requirements().find(|r| r.heading.span.contains(offset))?;
+            let changes = root
+                .map(|root| {
+                    requirement_changes(root, capability, &requirement.name).unwrap_or_else(|e| {
+                        eprintln!("[OpenSpec LSP] {:#}", e);
+                        Vec::new()
+                    })
+                })
+                .unwrap_or_default();
+            // The current file is the one being hovered, not history
+            let changes: Vec<_> = changes.into_iter().filter(|c| c.file != file).collect();
+            Some((requirement_hover(requirement, &changes, root.is_some()), requirement.heading.span))
+        }
+        SpecLocation::Change { change_id } if is_tasks_file(file) => {
+            let tasks = parse_tasks(text);
+            let task = tasks.task_at(offset)?;
+            let records = root
+                .map(|root| {
+                    load_audit_records(root).unwrap_or_else(|e| {
+                        eprintln!("[OpenSpec LSP] {:#}", e);
+                        Vec::new()
+                    })
+                })
+                .unwrap_or_default();
+            Some((task_hover(task, change_id, &records), task.span))
+        }
+        _ => None,
+    }
+}
+
+fn requirement_hover(requirement: &Requirement, changes: &[RequirementChange], has_workspace: bool) -> String {
+    let mut lines = vec![format!("**Requirement: {}**", requirement.name)];
+    let mut summary = format!("{} scenario(s)", requirement.scenarios.len());
+    if let Some(kind) = requirement.delta {
+        summary.push_str(&format!(", {} in this change", kind.as_str()));
+    }
+    lines.push(summary);
+    if !has_workspace {
+        return lines.join("\n\n");
+    }
+
+    let (archived, active): (Vec<_>, Vec<_>) = changes.iter().partition(|c| c.archived);
+    lines.push(change_list("Changes in progress", "Not modified by any active change", &active));
+    lines.push(change_list("Archive history", "No archived changes", &archived));
+    lines.join("\n\n")
+}
+
+fn change_list(title: &str, empty: &str, changes: &[&RequirementChange]) -> String {
+    if changes.is_empty() {
+        return format!("*{}*", empty);
+    }
+    let items: Vec<String> = changes
+        .iter()
+        .map(|c| format!("- `{}` ({})", c.change_id, c.kind.as_str()))
+        .collect();
+    format!("**{}**\n{}", title, items.join("\n"))
+}
+
+fn task_hover(task: &Task, change_id: &str, records: &[AuditRecord]) -> String {
+    let title = match &task.id {
+        Some(id) => format!("**Task {}**: {}", id, task.description),
+        None => format!("**Task**: {}", task.description),
+    };
+    let mut status = format!(
+        "Status: {}",
+        if task.completed { "complete" } else { "not complete" }
+    );
+    if !task.children.is_empty() {
+        let done = task.children.iter().filter(|t| t.completed).count();
+        status.push_str(&format!(", {}/{} subtask(s) complete", done, task.children.len()));
+    }
+
+    let Some(id) = &task.id else {
+        return format!("{}\n\n{}", title, status);
+    };
+    let linked: Vec<String> = records
+        .iter()
+        .filter(|r| r.covers_task(change_id, id))
+        .map(format_record)
+        .collect();
+    let audit = if linked.is_empty() {
+        "*No audit entries*".to_string()
+    } else {
+        format!("**Audit entries**\n{}", linked.join("\n"))
+    };
+    format!("{}\n\n{}\n\n{}", title, status, audit)
+}
+
+/// `- 2025-01-15T10:00:00Z claude (claude-sonnet-4), accepted`
+fn format_record(record: &AuditRecord) -> String {
+    let mut line = format!("- {}", if record.timestamp.is_empty() { &record.id } else { &record.timestamp });
+    if let Some(llm) = &record.llm {
+        line.push_str(&format!(" {}", llm.provider));
+        if !llm.model.is_empty() {
+            line.push_str(&format!(" ({})", llm.model));
+        }
+    }
+    if let Some(acceptance) = &record.acceptance {
+        line.push_str(if acceptance.accepted { ", accepted" } else { ", rejected" });
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write(root: &Path, file: &str, content: &str) {
+        let path = root.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_requirement_and_task_hover() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write(
+            root,
+            "openspec/changes/add-2fa/specs/auth/spec.md",
+            "## MODIFIED Requirements\n### Requirement: Login\nThe system SHALL ask for a code.\n",
+        );
+        write(
+            root,
+            ".openspec/audit/20250115_100000-b.json",
+            r#"{"id": "b", "timestamp": "2025-01-15T10:00:00Z", "change_id": "add-2fa", "task_ids": ["1.1"],
+                "llm": {"provider": "claude", "model": "claude-sonnet-4"}, "acceptance": {"accepted": true}}"#,
+        );
+
+        let spec = "# Auth\n\n### Requirement: Login\nThe system SHALL log users in.\n\n\
+                    #### Scenario: Success\n- **WHEN** valid\n- **THEN** a token is issued\n";
+        let offset = spec.find("Login").unwrap();
+        let (markdown, span) = hover_markdown("openspec/specs/auth/spec.md", spec, offset, Some(root)).unwrap();
+        assert_eq!(span.line_number(), 3);
+        assert_eq!(
+            markdown,
+            "**Requirement: Login**\n\n1 scenario(s)\n\n**Changes in progress**\n- `add-2fa` (MODIFIED)\n\n*No archived changes*"
+        );
+        assert!(hover_markdown("openspec/specs/auth/spec.md", spec, 0, Some(root)).is_none());
+
+        let tasks = "## 1. Setup\n- [x] 1.1 Add setting\n- [ ] 1.2 Add UI\n";
+        let file = "openspec/changes/add-2fa/tasks.md";
+        let (markdown, _) = hover_markdown(file, tasks, tasks.find("1.1").unwrap(), Some(root)).unwrap();
+        assert_eq!(
+            markdown,
+            "**Task 1.1**: Add setting\n\nStatus: complete\n\n**Audit entries**\n- 2025-01-15T10:00:00Z claude (claude-sonnet-4), accepted"
+        );
+        let (markdown, _) = hover_markdown(file, tasks, tasks.find("Add UI").unwrap(), None).unwrap();
+        assert!(markdown.ends_with("Status: not complete\n\n*No audit entries*"));
+    }
+}
@@ -0,0 +1,19 @@
+mod actions;
+mod convert;
+mod hover;
+mod navigation;
+mod server;
+mod symbols;
+
+use tower_lsp::{LspService, Server};
+
+#[tokio::main]
+async fn main() {
+    eprintln!("[OpenSpec LSP] Starting language server");
+
+    let stdin = tokio::io::stdin();
+    let stdout = tokio::io::stdout();
+
+    let (service, socket) = LspService::new(server::Backend::new);
+    Server::new(stdin, stdout, socket).serve(service).await;
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use tower_lsp::lsp_types::{Location, Url};
+use zed_openspec::spec::paths::{self, normalize_name, SpecLocation};
+use zed_openspec::spec::workspace::{delta_entries, workspace_files};
+use zed_openspec::spec::{parse_spec, LineIndex, Span};
+
+use crate::convert;
+
+/// Source spec requirement that the delta entry under `offset` refers to
+///
+/// Works on requirement headings in any delta section and on the FROM name
+/// of a rename. `file` is the workspace-relative path of the change spec;
+/// `open` maps workspace-relative paths of open documents to their text,
+/// which is used instead of what is on disk.
+pub fn definition(
+    file: &str,
+    text: &str,
+    offset: usize,
+    root: &Path,
+    open: &HashMap<String, String>,
+) -> Option<Location> {
+    let SpecLocation::Delta { capability, .. } = paths::classify(file) else {
+        return None;
+    };
+    let doc = parse_spec(text);
+    let name = doc.deltas.iter().find_map(|delta| {
+        let requirement = delta.requirements.iter().find(|r| r.heading.span.contains(offset));
+        let renamed = delta.renames.iter().filter_map(|r| r.from.as_ref()).find(|t| t.span.contains(offset));
+        requirement.map(|r| &r.name).or(renamed.map(|t| &t.name))
+    })?;
+
+    let source = paths::source_spec_path(capability);
+    let source_text = read(root, &source, open)?;
+    let span = source_heading(&source_text, name)?;
+    location(root, &source, &source_text, &span)
+}
+
+/// Active change specs that touch the requirement whose heading is under
+/// `offset`, preceded by its source spec heading when `include_declaration`
+///
+/// Open documents in `open` are read from their buffers, as in [`definition`].
+pub fn references(
+    file: &str,
+    text: &str,
+    offset: usize,
+    root: &Path,
+    open: &HashMap<String, String>,
+    include_declaration: bool,
+) -> Vec<Location> {
+    let capability = match paths::classify(file) {
+        SpecLocation::Source { capability } | SpecLocation::Delta { capability, .. } => capability,
+        _ => return Vec::new(),
+    };
+    let doc = parse_spec(text);
+    let Some(requirement) = doc.all_requirements().find(|r| r.heading.span.contains(offset)) else {
+        return Vec::new();
+    };
+    let text_of = |other: &str| if other == file { Some(text.to_string()) } else { read(root, other, open) };
+
+    let mut locations = Vec::new();
+    if include_declaration {
+        let source = paths::source_spec_path(capability);
+        if let Some(source_text) = text_of(&source) {
+            if let Some(span) = source_heading(&source_text, &requirement.name) {
+                locations.extend(location(root, &source, &source_text, &span));
+            }
+        }
+    }
+
+    // Open change specs may not be saved yet, so look at them as well as the disk
+    let mut files = workspace_files(root).unwrap_or_else(|e| {
+        eprintln!("[OpenSpec LSP] {:#}", e);
+        Vec::new()
+    });
+    files.extend(open.keys().cloned());
+    files.sort();
+    files.dedup();
+
+    let name = normalize_name(&requirement.name);
+    for change_file in files {
+        if !matches!(paths::classify(&change_file), SpecLocation::Delta { capability: c, .. } if c == capability) {
+            continue;
+        }
+        let Some(change_text) = text_of(&change_file) else {
+            continue;
+        };
+        for (_, span) in delta_entries(&parse_spec(&change_text), &name) {
+            locations.extend(location(root, &change_file, &change_text, &span));
+        }
+    }
+    locations
+}
+
+/// Text of a workspace file, from its open buffer if there is one
+fn read(root: &Path, file: &str, open: &HashMap<String, String>) -> Option<String> {
+    match open.get(file) {
+        Some(text) => Some(text.clone()),
+        None => fs::read_to_string(root.join(file)).ok(),
+    }
+}
+
+/// Heading of requirement `name` in a source spec
+fn source_heading(text: &str, name: &str) -> Option<Span> {
+    let name = normalize_name(name);
+    parse_spec(text)
+        .requirements
+        .iter()
+        .find(|r| normalize_name(&r.name) == name)
+        .map(|r| r.heading.span)
+}
+
+fn location(root: &Path, file: &str, text: &str, span: &Span) -> Option<Location> {
+    let uri = Url::from_file_path(root.join(file)).ok()?;
+    let index = LineIndex::new(text);
+    Some(Location {
+        uri,
+        range: convert::to_range(text, &index, span),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, file: &str, content: &str) {
+        let path = root.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_definition_and_references() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let source = "# Auth\n\n### Requirement: Login\nThe system SHALL log users in.\n";
+        let change = "## MODIFIED Requirements\n### Requirement: Login\nThe system SHALL ask for a code.\n\n\
+                      ## RENAMED Requirements\n- FROM: `### Requirement: Login`\n- TO: `### Requirement: Sign In`\n";
+        write(root, "openspec/specs/auth/spec.md", source);
+        write(root, "openspec/changes/add-2fa/specs/auth/spec.md", change);
+        write(
+            root,
+            "openspec/changes/archive/2025-01-01-add-login/specs/auth/spec.md",
+            "## ADDED Requirements\n### Requirement: Login\nThe system SHALL log users in.\n",
+        );
+
+        let open = HashMap::new();
+        let file = "openspec/changes/add-2fa/specs/auth/spec.md";
+        let target = definition(file, change, change.find("Login").unwrap(), root, &open).unwrap();
+        assert!(target.uri.path().ends_with("openspec/specs/auth/spec.md"));
+        assert_eq!(target.range.start.line, 2);
+        let from = change.rfind("Login").unwrap();
+        assert_eq!(definition(file, change, from, root, &open).unwrap().range.start.line, 2);
+        assert!(definition(file, change, change.find("Sign In").unwrap(), root, &open).is_none());
+
+        let offset = source.find("Login").unwrap();
+        let lines = |locations: Vec<Location>| -> Vec<(bool, u32)> {
+            locations
+                .iter()
+                .map(|l| (l.uri.path().contains("/changes/"), l.range.start.line))
+                .collect()
+        };
+        let source_file = "openspec/specs/auth/spec.md";
+        assert_eq!(lines(references(source_file, source, offset, root, &open, false)), vec![(true, 1), (true, 5)]);
+        assert_eq!(
+            lines(references(source_file, source, offset, root, &open, true)),
+            vec![(false, 2), (true, 1), (true, 5)]
+        );
+        assert!(references(source_file, source, 0, root, &open, true).is_empty());
+
+        // Unsaved edits, including a change spec not yet on disk, win over the disk
+        let open = HashMap::from([
+            (file.to_string(), format!("# Notes\n\n{}", change)),
+            (
+                "openspec/changes/fix-login/specs/auth/spec.md".to_string(),
+                "## REMOVED Requirements\n### Requirement: Login\n".to_string(),
+            ),
+        ]);
+        assert_eq!(
+            lines(references(source_file, source, offset, root, &open, false)),
+            vec![(true, 3), (true, 7), (true, 1)]
+        );
+    }
+}
@@ -0,0 +1,600 @@
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tower_lsp::jsonrpc::Result;
+use tower_lsp::lsp_types::*;
+use tower_lsp::{Client, LanguageServer};
+use zed_openspec::spec::LineIndex;
+use zed_openspec::utils::config::ExtensionConfig;
+use zed_openspec::utils::layers::user_config_path;
+use zed_openspec::validation::custom::{CustomRules, CUSTOM_RULES_FILE};
+use zed_openspec::validation::Validator;
+
+use crate::symbols::{self, SymbolIndex};
+use crate::{actions, convert, hover, navigation};
+
+/// Open document tracked by the server
+struct Document {
+    text: String,
+    version: i32,
+}
+
+struct State {
+    config: ExtensionConfig,
+    /// Rules from `.openspec/validation-rules.json`, reloaded with the config
+    custom_rules: CustomRules,
+    root: Option<PathBuf>,
+    documents: HashMap<Url, Document>,
+    /// Workspace symbols, built in `initialized` and updated per document
+    symbols: SymbolIndex,
+}
+
+impl State {
+    /// Load the config layers and custom rules of the workspace
+    fn load_settings(&mut self) {
+        let Some(root) = &self.root else {
+            return;
+        };
+        self.config = ExtensionConfig::load_or_default(root);
+        self.custom_rules = CustomRules::load(root).unwrap_or_else(|e| {
+            eprintln!("[OpenSpec LSP] {:#}; custom rules disabled", e);
+            CustomRules::default()
+        });
+    }
+
+    /// Text of every open document, keyed by workspace-relative path
+    fn open_texts(&self, root: &Path) -> HashMap<String, String> {
+        self.documents
+            .iter()
+            .filter_map(|(uri, document)| {
+                let path = uri.to_file_path().ok()?;
+                Some((relative_path(&path, Some(root)), document.text.clone()))
+            })
+            .collect()
+    }
+}
+
+/// OpenSpec language server
+///
+/// Publishes validation diagnostics for markdown files under `openspec/`.
+/// Edits are validated after `validation.debounce_ms` of inactivity; opens
+/// and saves are validated immediately. Saving a config or custom rules file
+/// reloads the settings and revalidates every open document.
+///
+/// Hovering a requirement heading shows its scenarios and the changes that
+/// touch it; hovering a task shows its status and audit entries. Common
+/// findings come with quick fixes, and the outline shows delta sections,
+/// requirements, scenarios and tasks. Workspace symbol search covers every
+/// requirement and scenario outside the archive, and delta requirements link
+/// to their source spec through go-to-definition and find-references.
+#[derive(Clone)]
+pub struct Backend {
+    client: Client,
+    state: Arc<Mutex<State>>,
+}
+
+impl Backend {
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            state: Arc::new(Mutex::new(State {
+                config: ExtensionConfig::default(),
+                custom_rules: CustomRules::default(),
+                root: None,
+                documents: HashMap::new(),
+                symbols: SymbolIndex::default(),
+            })),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Text, workspace root and workspace-relative path of an open document,
+    /// plus the text of all open documents, copied out so that lookups
+    /// reading other files don't hold the lock
+    fn document_in_workspace(&self, uri: &Url) -> Option<(String, PathBuf, String, HashMap<String, String>)> {
+        let state = self.state();
+        let text = state.documents.get(uri)?.text.clone();
+        let root = state.root.clone()?;
+        let file = relative_path(&uri.to_file_path().ok()?, Some(&root));
+        let open = state.open_texts(&root);
+        Some((text, root, file, open))
+    }
+
+    /// Validate the current text of a document and publish the results
+    async fn publish(&self, uri: Url) {
+        let (diagnostics, version) = {
+            let state = self.state();
+            let Some(document) = state.documents.get(&uri) else {
+                return;
+            };
+            (
+                compute_diagnostics(
+                    &uri,
+                    &document.text,
+                    &state.config,
+                    &state.custom_rules,
+                    state.root.as_deref(),
+                ),
+                document.version,
+            )
+        };
+
+        self.client
+            .publish_diagnostics(uri, diagnostics, Some(version))
+            .await;
+    }
+
+    /// Reload the settings and revalidate every open document
+    async fn reload_settings(&self) {
+        let uris: Vec<Url> = {
+            let mut state = self.state();
+            state.load_settings();
+            state.documents.keys().cloned().collect()
+        };
+        for uri in uris {
+            self.publish(uri).await;
+        }
+    }
+
+    /// Re-index a spec's symbols from its open buffer, or from disk when it
+    /// isn't open
+    fn reindex(&self, uri: &Url) {
+        let mut guard = self.state();
+        let state = &mut *guard;
+        let (Some(root), Ok(path)) = (&state.root, uri.to_file_path()) else {
+            return;
+        };
+        if !is_openspec_file(&path) {
+            return;
+        }
+        let file = relative_path(&path, Some(root));
+        match state.documents.get(uri) {
+            Some(document) => state.symbols.update(root, &file, Some(&document.text)),
+            None => state.symbols.refresh(root, &file),
+        }
+    }
+
+    /// Whether `uri` is one of the files the settings are loaded from
+    fn is_settings_uri(&self, uri: &Url) -> bool {
+        let Some(root) = self.state().root.clone() else {
+            return false;
+        };
+        uri.to_file_path().is_ok_and(|path| settings_files(&root).contains(&path))
+    }
+
+    /// Validate after the debounce delay, unless the document changed again meanwhile
+    fn schedule(&self, uri: Url, version: i32) {
+        let delay = Duration::from_millis(self.state().config.validation.debounce_ms);
+        let backend = self.clone();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let current = backend
+                .state()
+                .documents
+                .get(&uri)
+                .map(|d| d.version);
+            if current == Some(version) {
+                backend.publish(uri).await;
+            }
+        });
+    }
+}
+
+#[tower_lsp::async_trait]
+impl LanguageServer for Backend {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        #[allow(deprecated)]
+        let root = params
+            .workspace_folders
+            .as_ref()
+            .and_then(|folders| folders.first())
+            .map(|folder| folder.uri.clone())
+            .or(params.root_uri)
+            .and_then(|uri| uri.to_file_path().ok());
+        {
+            let mut state = self.state();
+            state.root = root;
+            state.load_settings();
+        }
+
+        Ok(InitializeResult {
+            capabilities: ServerCapabilities {
+                text_document_sync: Some(TextDocumentSyncCapability::Options(
+                    TextDocumentSyncOptions {
+                        open_close: Some(true),
+                        change: Some(TextDocumentSyncKind::FULL),
+                        save: Some(TextDocumentSyncSaveOptions::SaveOptions(SaveOptions {
+                            include_text: Some(true),
+                        })),
+                        ..Default::default()
+                    },
+                )),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+                    code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            },
+            server_info: Some(ServerInfo {
+                name: "openspec-lsp".to_string(),
+                version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            }),
+        })
+    }
+
+    async fn initialized(&self, _: InitializedParams) {
+        let root = self.state().root.clone();
+        self.client
+            .log_message(
+                MessageType::INFO,
+                format!("[OpenSpec LSP] Initialized for workspace: {:?}", root),
+            )
+            .await;
+
+        // Settings files are JSON, so editors don't report saves of them to a
+        // markdown server; watch them instead, along with specs
+        let Some(root) = root else {
+            return;
+        };
+        let symbols = SymbolIndex::build(&root);
+        let open: Vec<Url> = {
+            let mut state = self.state();
+            state.symbols = symbols;
+            state.documents.keys().cloned().collect()
+        };
+        for uri in &open {
+            self.reindex(uri);
+        }
+
+        // Specs can also change on disk, e.g. on branch switches
+        let specs = root.join("openspec").join("**").join("*.md");
+        let watchers = settings_files(&root)
+            .into_iter()
+            .chain([specs])
+            .map(|path| FileSystemWatcher {
+                glob_pattern: GlobPattern::String(path.to_string_lossy().into_owned()),
+                kind: None,
+            })
+            .collect();
+        let registration = Registration {
+            id: "openspec-settings".to_string(),
+            method: "workspace/didChangeWatchedFiles".to_string(),
+            register_options: serde_json::to_value(DidChangeWatchedFilesRegistrationOptions { watchers }).ok(),
+        };
+        if let Err(e) = self.client.register_capability(vec![registration]).await {
+            eprintln!("[OpenSpec LSP] Not watching settings and spec files: {}", e);
+        }
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let document = params.text_document;
+        self.state().documents.insert(
+            document.uri.clone(),
+            Document {
+                text: document.text,
+                version: document.version,
+            },
+        );
+        self.reindex(&document.uri);
+        self.publish(document.uri).await;
+    }
+
+    async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        let uri = params.text_document.uri;
+        let version = params.text_document.version;
+
+        // Full sync: the last change carries the whole document
+        let Some(change) = params.content_changes.into_iter().last() else {
+            return;
+        };
+        self.state().documents.insert(
+            uri.clone(),
+            Document {
+                text: change.text,
+                version,
+            },
+        );
+        self.reindex(&uri);
+        self.schedule(uri, version);
+    }
+
+    async fn did_save(&self, params: DidSaveTextDocumentParams) {
+        let uri = params.text_document.uri;
+        if let Some(text) = params.text {
+            if let Some(document) = self.state().documents.get_mut(&uri) {
+                document.text = text;
+            }
+        }
+        if self.is_settings_uri(&uri) {
+            self.reload_settings().await;
+            return;
+        }
+        self.reindex(&uri);
+        self.publish(uri).await;
+    }
+
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        for change in &params.changes {
+            self.reindex(&change.uri);
+        }
+        if params.changes.iter().any(|change| self.is_settings_uri(&change.uri)) {
+            self.reload_settings().await;
+        }
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let uri = params.text_document.uri;
+        self.state().documents.remove(&uri);
+        // Unsaved edits are gone, so index what is on disk again
+        self.reindex(&uri);
+        self.client.publish_diagnostics(uri, Vec::new(), None).await;
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let position = params.text_document_position_params;
+        let uri = position.text_document.uri;
+        // Hover reads other files, so don't hold the lock meanwhile
+        let (text, root) = {
+            let state = self.state();
+            let Some(document) = state.documents.get(&uri) else {
+                return Ok(None);
+            };
+            (document.text.clone(), state.root.clone())
+        };
+        let path = match uri.to_file_path() {
+            Ok(path) if is_openspec_file(&path) => path,
+            _ => return Ok(None),
+        };
+
+        let index = LineIndex::new(&text);
+        let offset = convert::to_offset(&text, &index, position.position);
+        let file = relative_path(&path, root.as_deref());
+        let hover = hover::hover_markdown(&file, &text, offset, root.as_deref()).map(|(markdown, span)| Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: markdown,
+            }),
+            range: Some(convert::to_range(&text, &index, &span)),
+        });
+        Ok(hover)
+    }
+
+    async fn goto_definition(&self, params: GotoDefinitionParams) -> Result<Option<GotoDefinitionResponse>> {
+        let position = params.text_document_position_params;
+        let Some((text, root, file, open)) = self.document_in_workspace(&position.text_document.uri) else {
+            return Ok(None);
+        };
+
+        let index = LineIndex::new(&text);
+        let offset = convert::to_offset(&text, &index, position.position);
+        Ok(navigation::definition(&file, &text, offset, &root, &open).map(GotoDefinitionResponse::Scalar))
+    }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let position = params.text_document_position;
+        let Some((text, root, file, open)) = self.document_in_workspace(&position.text_document.uri) else {
+            return Ok(None);
+        };
+
+        let index = LineIndex::new(&text);
+        let offset = convert::to_offset(&text, &index, position.position);
+        let include_declaration = params.context.include_declaration;
+        Ok(Some(navigation::references(&file, &text, offset, &root, &open, include_declaration)))
+    }
+
+    async fn document_symbol(&self, params: DocumentSymbolParams) -> Result<Option<DocumentSymbolResponse>> {
+        let uri = params.text_document.uri;
+        let state = self.state();
+        let (Some(document), Ok(path)) = (state.documents.get(&uri), uri.to_file_path()) else {
+            return Ok(None);
+        };
+        if !is_openspec_file(&path) {
+            return Ok(None);
+        }
+
+        let file = relative_path(&path, state.root.as_deref());
+        let symbols = symbols::document_symbols(&file, &document.text);
+        Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+    }
+
+    async fn symbol(&self, params: WorkspaceSymbolParams) -> Result<Option<Vec<SymbolInformation>>> {
+        let state = self.state();
+        if state.root.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(state.symbols.query(&params.query)))
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = params.text_document.uri;
+        let (text, root) = {
+            let state = self.state();
+            let Some(document) = state.documents.get(&uri) else {
+                return Ok(None);
+            };
+            (document.text.clone(), state.root.clone())
+        };
+        let path = match uri.to_file_path() {
+            Ok(path) if is_openspec_file(&path) => path,
+            _ => return Ok(None),
+        };
+
+        let index = LineIndex::new(&text);
+        let file = relative_path(&path, root.as_deref());
+        let mut response = Vec::new();
+        for diagnostic in params.context.diagnostics {
+            let Some(NumberOrString::String(rule_id)) = &diagnostic.code else {
+                continue;
+            };
+            if diagnostic.source.as_deref() != Some("openspec") {
+                continue;
+            }
+            let range = convert::to_offset(&text, &index, diagnostic.range.start)
+                ..convert::to_offset(&text, &index, diagnostic.range.end);
+            let Some(fix) = actions::quick_fix(rule_id, &file, &text, range, root.as_deref()) else {
+                continue;
+            };
+
+            let edits = fix
+                .edits
+                .into_iter()
+                .map(|(range, new_text)| TextEdit {
+                    range: Range::new(
+                        convert::to_position(&text, &index, range.start),
+                        convert::to_position(&text, &index, range.end),
+                    ),
+                    new_text,
+                })
+                .collect();
+            response.push(CodeActionOrCommand::CodeAction(CodeAction {
+                title: fix.title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![diagnostic.clone()]),
+                edit: Some(WorkspaceEdit {
+                    changes: Some(HashMap::from([(uri.clone(), edits)])),
+                    ..Default::default()
+                }),
+                is_preferred: Some(true),
+                ..Default::default()
+            }));
+        }
+        Ok(Some(response))
+    }
+}
+
+/// Validate a document, returning no diagnostics for files outside `openspec/`
+/// or with validation turned off
+fn compute_diagnostics(
+    uri: &Url,
+    text: &str,
+    config: &ExtensionConfig,
+    custom_rules: &CustomRules,
+    root: Option<&Path>,
+) -> Vec<Diagnostic> {
+    let path = match uri.to_file_path() {
+        Ok(path) if is_openspec_file(&path) => path,
+        _ => return Vec::new(),
+    };
+    let file = relative_path(&path, root);
+    let Some(rules) = config.validation.rules_for(&file) else {
+        return Vec::new();
+    };
+
+    let index = LineIndex::new(text);
+    let validator = Validator::new(&rules).with_custom_rules(custom_rules);
+    let validator = match root {
+        Some(root) => validator.with_workspace(root),
+        None => validator,
+    };
+    validator
+        .validate_source(&file, text)
+        .iter()
+        .map(|d| convert::to_diagnostic(text, &index, d))
+        .collect()
+}
+
+/// Workspace-relative path with `/` separators, falling back to the full path
+fn relative_path(path: &Path, root: Option<&Path>) -> String {
+    let relative = root
+        .and_then(|root| path.strip_prefix(root).ok())
+        .unwrap_or(path);
+    relative
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(name) => name.to_str(),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Files the server's settings are loaded from: the workspace config, the
+/// user config and the custom rules
+fn settings_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = vec![ExtensionConfig::path(root), root.join(CUSTOM_RULES_FILE)];
+    files.extend(user_config_path());
+    files
+}
+
+/// Check whether a path is a markdown file inside an `openspec/` directory
+fn is_openspec_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "md")
+        && path
+            .components()
+            .any(|c| matches!(c, Component::Normal(name) if name == "openspec"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zed_openspec::utils::config::ValidationOverride;
+
+    const SPEC: &str = "### Requirement: Login\nThe system SHALL log users in.\n";
+
+    fn uri(path: &str) -> Url {
+        Url::from_file_path(path).unwrap()
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let path = Path::new("/repo/openspec/specs/auth/spec.md");
+        assert_eq!(relative_path(path, Some(Path::new("/repo"))), "openspec/specs/auth/spec.md");
+        assert_eq!(relative_path(path, None), "repo/openspec/specs/auth/spec.md");
+    }
+
+    #[test]
+    fn test_only_openspec_markdown_is_validated() {
+        assert!(is_openspec_file(Path::new("/repo/openspec/specs/auth/spec.md")));
+        assert!(!is_openspec_file(Path::new("/repo/docs/spec.md")));
+        assert!(!is_openspec_file(Path::new("/repo/openspec/project.json")));
+    }
+
+    #[test]
+    fn test_settings_files() {
+        let files = settings_files(Path::new("/repo"));
+        assert!(files.contains(&PathBuf::from("/repo/.openspec-config.json")));
+        assert!(files.contains(&PathBuf::from("/repo/.openspec/validation-rules.json")));
+        assert!(!files.contains(&PathBuf::from("/repo/openspec/specs/auth/spec.md")));
+    }
+
+    #[test]
+    fn test_compute_diagnostics_honors_enabled_flag() {
+        let mut config = ExtensionConfig::default();
+        let custom = CustomRules::default();
+        let spec_uri = uri("/repo/openspec/specs/auth/spec.md");
+
+        let root = Some(Path::new("/repo"));
+
+        let diagnostics = compute_diagnostics(&spec_uri, SPEC, &config, &custom, root);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start, Position::new(0, 0));
+        assert_eq!(diagnostics[0].code, Some(NumberOrString::String("OS001".to_string())));
+
+        assert!(compute_diagnostics(&uri("/repo/README.md"), SPEC, &config, &custom, root).is_empty());
+
+        config
+            .validation
+            .overrides
+            .push(ValidationOverride::new(vec!["openspec/specs/auth/**".to_string()]).with_enabled(false));
+        assert!(compute_diagnostics(&spec_uri, SPEC, &config, &custom, root).is_empty());
+
+        config.validation.overrides.clear();
+        config.validation.enabled = false;
+        assert!(compute_diagnostics(&spec_uri, SPEC, &config, &custom, root).is_empty());
+    }
+}
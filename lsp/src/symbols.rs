@@ -0,0 +1,373 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use tower_lsp::lsp_types::{DocumentSymbol, Location, SymbolInformation, SymbolKind, Url};
+use zed_openspec::spec::paths::{self, SpecLocation};
+use zed_openspec::spec::tasks::{parse_tasks, Task};
+use zed_openspec::spec::workspace::workspace_files;
+use zed_openspec::spec::{parse_spec, LineIndex, Requirement, Scenario, Span};
+use zed_openspec::validation::is_tasks_file;
+
+use crate::convert;
+
+/// Outline of a spec or tasks file
+///
+/// Specs nest scenarios under requirements under delta sections; `tasks.md`
+/// nests tasks under their numbered section.
+pub fn document_symbols(file: &str, text: &str) -> Vec<DocumentSymbol> {
+    let outline = Outline { text, index: LineIndex::new(text) };
+    if is_tasks_file(file) {
+        outline.tasks()
+    } else {
+        outline.spec()
+    }
+}
+
+/// Requirements and scenarios of every source spec and active change spec
+///
+/// Built once from disk and then kept current as documents are edited,
+/// saved and closed, so queries never walk the workspace. Open documents are
+/// indexed from their buffers, including ones not saved to disk yet.
+#[derive(Default)]
+pub struct SymbolIndex {
+    /// Symbols by workspace-relative path, in path order
+    files: BTreeMap<String, Vec<SymbolInformation>>,
+}
+
+impl SymbolIndex {
+    /// Index every spec under `root` as it is on disk
+    pub fn build(root: &Path) -> Self {
+        let mut index = Self::default();
+        let files = workspace_files(root).unwrap_or_else(|e| {
+            eprintln!("[OpenSpec LSP] {:#}", e);
+            Vec::new()
+        });
+        for file in files {
+            index.refresh(root, &file);
+        }
+        index
+    }
+
+    /// Re-index a file from `text`, or drop it when `text` is `None`
+    ///
+    /// Files other than source and active change specs are ignored.
+    pub fn update(&mut self, root: &Path, file: &str, text: Option<&str>) {
+        match text.and_then(|text| file_symbols(root, file, text)) {
+            Some(symbols) => {
+                self.files.insert(file.to_string(), symbols);
+            }
+            None => {
+                self.files.remove(file);
+            }
+        }
+    }
+
+    /// Re-index a file from disk, e.g. after its buffer is closed
+    pub fn refresh(&mut self, root: &Path, file: &str) {
+        let text = std::fs::read_to_string(root.join(file)).ok();
+        self.update(root, file, text.as_deref());
+    }
+
+    /// Symbols whose name contains every word of `query`, ignoring case
+    pub fn query(&self, query: &str) -> Vec<SymbolInformation> {
+        let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        self.files
+            .values()
+            .flatten()
+            .filter(|symbol| {
+                let name = symbol.name.to_lowercase();
+                words.iter().all(|word| name.contains(word.as_str()))
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Requirements, then their scenarios, of a source or active change spec
+fn file_symbols(root: &Path, file: &str, text: &str) -> Option<Vec<SymbolInformation>> {
+    let container = match paths::classify(file) {
+        SpecLocation::Source { capability } => capability.to_string(),
+        SpecLocation::Delta { change_id, capability } => format!("{} ({})", capability, change_id),
+        _ => return None,
+    };
+    let uri = Url::from_file_path(root.join(file)).ok()?;
+
+    let index = LineIndex::new(text);
+    let location = |span: &Span| Location {
+        uri: uri.clone(),
+        range: convert::to_range(text, &index, span),
+    };
+    let mut symbols = Vec::new();
+    for requirement in parse_spec(text).all_requirements() {
+        symbols.push(symbol_information(
+            &requirement.name,
+            SymbolKind::CLASS,
+            location(&requirement.span),
+            &container,
+        ));
+        for scenario in &requirement.scenarios {
+            symbols.push(symbol_information(
+                &scenario.name,
+                SymbolKind::METHOD,
+                location(&scenario.span),
+                &requirement.name,
+            ));
+        }
+    }
+    Some(symbols)
+}
+
+fn symbol_information(name: &str, kind: SymbolKind, location: Location, container: &str) -> SymbolInformation {
+    #[allow(deprecated)]
+    SymbolInformation {
+        name: name.to_string(),
+        kind,
+        tags: None,
+        deprecated: None,
+        location,
+        container_name: Some(container.to_string()),
+    }
+}
+
+struct Outline<'a> {
+    text: &'a str,
+    index: LineIndex<'a>,
+}
+
+impl Outline<'_> {
+    fn spec(&self) -> Vec<DocumentSymbol> {
+        let doc = parse_spec(self.text);
+        let mut symbols: Vec<(usize, DocumentSymbol)> = Vec::new();
+
+        for delta in &doc.deltas {
+            let mut children: Vec<DocumentSymbol> = delta.requirements.iter().map(|r| self.requirement(r)).collect();
+            for rename in &delta.renames {
+                let from = rename.from.as_ref().map(|t| t.name.as_str()).unwrap_or("?");
+                let to = rename.to.as_ref().map(|t| t.name.as_str()).unwrap_or("?");
+                children.push(self.symbol(
+                    format!("{} → {}", from, to),
+                    None,
+                    SymbolKind::PROPERTY,
+                    rename.span,
+                    rename.span,
+                    Vec::new(),
+                ));
+            }
+            children.sort_by_key(|s| (s.range.start.line, s.range.start.character));
+            symbols.push((
+                delta.span.start,
+                self.symbol(
+                    delta.heading.text.clone(),
+                    None,
+                    SymbolKind::NAMESPACE,
+                    delta.span,
+                    delta.heading.text_span,
+                    children,
+                ),
+            ));
+        }
+        for requirement in &doc.requirements {
+            symbols.push((requirement.span.start, self.requirement(requirement)));
+        }
+        for scenario in &doc.orphan_scenarios {
+            symbols.push((scenario.span.start, self.scenario(scenario)));
+        }
+
+        symbols.sort_by_key(|(start, _)| *start);
+        symbols.into_iter().map(|(_, symbol)| symbol).collect()
+    }
+
+    fn requirement(&self, requirement: &Requirement) -> DocumentSymbol {
+        self.symbol(
+            requirement.name.clone(),
+            Some(format!("{} scenario(s)", requirement.scenarios.len())),
+            SymbolKind::CLASS,
+            requirement.span,
+            requirement.name_span,
+            requirement.scenarios.iter().map(|s| self.scenario(s)).collect(),
+        )
+    }
+
+    fn scenario(&self, scenario: &Scenario) -> DocumentSymbol {
+        self.symbol(
+            scenario.name.clone(),
+            None,
+            SymbolKind::METHOD,
+            scenario.span,
+            scenario.name_span,
+            Vec::new(),
+        )
+    }
+
+    fn tasks(&self) -> Vec<DocumentSymbol> {
+        let list = parse_tasks(self.text);
+        let mut symbols = Vec::new();
+        for section in &list.sections {
+            let tasks: Vec<DocumentSymbol> = section.tasks.iter().map(|t| self.task(t)).collect();
+            // Tasks before the first heading have no section of their own
+            if section.title.is_empty() && section.number.is_none() {
+                symbols.extend(tasks);
+                continue;
+            }
+
+            let name = match &section.number {
+                Some(number) => format!("{}. {}", number, section.title),
+                None => section.title.clone(),
+            };
+            let end = section.tasks.last().map(last_line).unwrap_or(section.span);
+            symbols.push(self.symbol(
+                name,
+                None,
+                SymbolKind::NAMESPACE,
+                self.index.span(section.span.start, end.end),
+                section.span,
+                tasks,
+            ));
+        }
+        symbols
+    }
+
+    fn task(&self, task: &Task) -> DocumentSymbol {
+        let end = last_line(task);
+        self.symbol(
+            task.label(),
+            Some(if task.completed { "done" } else { "open" }.to_string()),
+            SymbolKind::EVENT,
+            self.index.span(task.span.start, end.end),
+            task.span,
+            task.children.iter().map(|t| self.task(t)).collect(),
+        )
+    }
+
+    fn symbol(
+        &self,
+        name: String,
+        detail: Option<String>,
+        kind: SymbolKind,
+        span: Span,
+        selection: Span,
+        children: Vec<DocumentSymbol>,
+    ) -> DocumentSymbol {
+        #[allow(deprecated)]
+        DocumentSymbol {
+            // Editors reject empty names, e.g. for a heading still being typed
+            name: if name.trim().is_empty() { "(unnamed)".to_string() } else { name },
+            detail,
+            kind,
+            tags: None,
+            deprecated: None,
+            range: convert::to_range(self.text, &self.index, &span),
+            selection_range: convert::to_range(self.text, &self.index, &selection),
+            children: if children.is_empty() { None } else { Some(children) },
+        }
+    }
+}
+
+/// Checkbox line of a task's last descendant, or the task's own line
+fn last_line(task: &Task) -> Span {
+    task.children.last().map(last_line).unwrap_or(task.span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outline(symbols: &[DocumentSymbol], depth: usize, out: &mut Vec<String>) {
+        for symbol in symbols {
+            out.push(format!("{}{} ({}-{})", "  ".repeat(depth), symbol.name, symbol.range.start.line, symbol.range.end.line));
+            outline(symbol.children.as_deref().unwrap_or_default(), depth + 1, out);
+        }
+    }
+
+    #[test]
+    fn test_workspace_symbols() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let write = |file: &str, content: &str| {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        };
+        write(
+            "openspec/specs/auth/spec.md",
+            "### Requirement: User Login\nThe system SHALL log users in.\n\n#### Scenario: Login fails\n- **WHEN** wrong\n",
+        );
+        write(
+            "openspec/changes/add-2fa/specs/auth/spec.md",
+            "## ADDED Requirements\n### Requirement: Login Codes\nThe system SHALL send codes.\n",
+        );
+        write(
+            "openspec/changes/archive/2025-01-01-old/specs/auth/spec.md",
+            "## ADDED Requirements\n### Requirement: Login\nThe system SHALL log users in.\n",
+        );
+
+        let names = |symbols: Vec<SymbolInformation>| -> Vec<(String, String)> {
+            symbols.into_iter().map(|s| (s.name, s.container_name.unwrap())).collect()
+        };
+        let mut index = SymbolIndex::build(root);
+        assert_eq!(
+            names(index.query("LOGIN")),
+            vec![
+                ("Login Codes".to_string(), "auth (add-2fa)".to_string()),
+                ("User Login".to_string(), "auth".to_string()),
+                ("Login fails".to_string(), "User Login".to_string()),
+            ]
+        );
+        assert_eq!(names(index.query("login fail")).len(), 1);
+
+        // Unsaved buffers, including specs not on disk yet, replace what is on disk
+        let source = "openspec/specs/auth/spec.md";
+        index.update(root, source, Some("### Requirement: Sign In\nThe system SHALL log users in.\n"));
+        index.update(
+            root,
+            "openspec/changes/add-sso/specs/auth/spec.md",
+            Some("## ADDED Requirements\n### Requirement: Sign In With SSO\n"),
+        );
+        index.update(root, "openspec/changes/add-sso/tasks.md", Some("- [ ] 1.1 Sign in\n"));
+        let symbols = index.query("sign");
+        assert_eq!(symbols.len(), 2);
+        assert!(symbols[1].location.uri.path().ends_with(source));
+        assert!(index.query("user login").is_empty());
+
+        // Closing a buffer falls back to the disk
+        index.refresh(root, source);
+        index.refresh(root, "openspec/changes/add-sso/specs/auth/spec.md");
+        assert!(index.query("sign").is_empty());
+        assert_eq!(index.query("user login").len(), 1);
+    }
+
+    #[test]
+    fn test_spec_and_task_outlines() {
+        let spec = "# Auth\n\n## ADDED Requirements\n### Requirement: Login\nThe system SHALL log users in.\n\n\
+                    #### Scenario: Success\n- **WHEN** valid\n- **THEN** a token is issued\n\n\
+                    ## RENAMED Requirements\n- FROM: `### Requirement: Logout`\n- TO: `### Requirement: Sign Out`\n";
+        let mut lines = Vec::new();
+        outline(&document_symbols("openspec/changes/add-2fa/specs/auth/spec.md", spec), 0, &mut lines);
+        assert_eq!(
+            lines,
+            vec![
+                "ADDED Requirements (2-8)",
+                "  Login (3-8)",
+                "    Success (6-8)",
+                "RENAMED Requirements (10-12)",
+                "  Logout → Sign Out (11-12)",
+            ]
+        );
+
+        let tasks = "- [ ] Prep\n## 1. Setup\n- [x] 1.1 Add setting\n  - [ ] 1.1.1 Migrate\n- [ ] 1.2 Add UI\n";
+        let symbols = document_symbols("openspec/changes/add-2fa/tasks.md", tasks);
+        let mut lines = Vec::new();
+        outline(&symbols, 0, &mut lines);
+        assert_eq!(
+            lines,
+            vec![
+                "Prep (0-0)",
+                "1. Setup (1-4)",
+                "  1.1 Add setting (2-3)",
+                "    1.1.1 Migrate (3-3)",
+                "  1.2 Add UI (4-4)",
+            ]
+        );
+        assert_eq!(symbols[1].children.as_ref().unwrap()[0].detail.as_deref(), Some("done"));
+    }
+}
@@ -1,7 +1,64 @@
-use anyhow::{Result, Context};
+use anyhow::Result;
+use serde::Serialize;
 use std::path::Path;
 
+use super::output::{CommandOutput, PendingTask};
+use crate::spec::tasks::parse_tasks;
 use crate::utils::config::ExtensionConfig;
+use crate::utils::fs::read_file;
+
+/// Result of `openspec:apply-change`
+#[derive(Debug, Clone, Serialize)]
+pub struct ApplyResult {
+    pub change_id: String,
+    pub provider: String,
+    /// Whether code was generated; always false until LLM integration lands
+    pub generated: bool,
+    /// Task progress, if the change has a `tasks.md`
+    pub tasks: Option<TaskProgress>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskProgress {
+    pub completed: usize,
+    pub total: usize,
+    pub pending: Vec<PendingTask>,
+}
+
+impl CommandOutput for ApplyResult {
+    const COMMAND: &'static str = "apply-change";
+
+    fn to_text(&self) -> String {
+        let progress = match &self.tasks {
+            Some(tasks) => format!(
+                "Tasks: {}/{} complete\n{}\n\n",
+                tasks.completed,
+                tasks.total,
+                tasks.pending
+                    .iter()
+                    .map(|t| format!("- [ ] {}", t.label))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ),
+            None => String::new(),
+        };
+
+        format!(
+            "Code generation for change '{}' is not yet implemented.\n\n\
+            {}\
+            This feature will be available in Phase 3 of development.\n\n\
+            For now, you can:\n\
+            1. Manually implement the tasks in openspec/changes/{}/tasks.md\n\
+            2. Mark tasks as complete in tasks.md\n\
+            3. Run 'openspec:archive-change' when all tasks are done\n\n\
+            LLM Provider configured: {}",
+            self.change_id,
+            progress,
+            self.change_id,
+            self.provider
+        )
+    }
+}
 
 /// Handle `openspec:apply-change` command
 /// Generates code for a change using LLM
@@ -9,8 +66,8 @@ pub fn handle_apply_change(
     workspace_path: &Path,
     change_id: &str,
     llm_provider: &str,
-    config: &ExtensionConfig,
-) -> Result<String> {
+    _config: &ExtensionConfig,
+) -> Result<ApplyResult> {
     eprintln!("[OpenSpec] Applying change: {} with provider: {}", change_id, llm_provider);
 
     // Verify change exists
@@ -22,20 +79,24 @@ pub fn handle_apply_change(
         ));
     }
 
+    let tasks_file = change_dir.join("tasks.md");
+    let tasks = if tasks_file.exists() {
+        let tasks = parse_tasks(&read_file(&tasks_file)?);
+        Some(TaskProgress {
+            completed: tasks.completed_count(),
+            total: tasks.total(),
+            pending: tasks.incomplete().into_iter().map(PendingTask::from).collect(),
+        })
+    } else {
+        None
+    };
+
     // This is a placeholder for Phase 1
     // Full LLM integration will be implemented in Phase 3
-    Ok(format!(
-        "Code generation for change '{}' is not yet implemented.\n\n\
-        This feature will be available in Phase 3 of development.\n\n\
-        For now, you can:\n\
-        1. Manually implement the tasks in openspec/changes/{}/tasks.md\n\
-        2. Mark tasks as complete in tasks.md\n\
-        3. Run 'openspec:archive-change' when all tasks are done\n\n\
-        LLM Provider configured: {}\n\
-        Configuration: {:?}",
-        change_id,
-        change_id,
-        llm_provider,
-        config.llm
-    ))
+    Ok(ApplyResult {
+        change_id: change_id.to_string(),
+        provider: llm_provider.to_string(),
+        generated: false,
+        tasks,
+    })
 }
@@ -1,10 +1,76 @@
 use anyhow::{Result, Context};
+use serde::Serialize;
+use std::fs::OpenOptions;
+use std::io::Write;
 use std::path::Path;
 use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::output::{CommandOutput, PendingTask};
+use crate::spec::tasks::parse_tasks;
+use crate::utils::config::ExtensionConfig;
+
+/// Log of archives that skipped the task completion check
+const OVERRIDE_LOG: &str = "archive-overrides.jsonl";
+
+/// Record written when an archive is forced past incomplete tasks
+#[derive(Debug, Serialize)]
+struct ArchiveOverride<'a> {
+    change_id: &'a str,
+    timestamp: u64,
+    user: Option<String>,
+    incomplete_tasks: Vec<String>,
+}
+
+/// Result of `openspec:archive-change`
+#[derive(Debug, Clone, Serialize)]
+pub struct ArchiveResult {
+    pub change_id: String,
+    /// Output of `openspec archive`
+    pub output: String,
+    /// Tasks still unchecked when the archive was forced with `--force`
+    pub forced_incomplete_tasks: Vec<PendingTask>,
+    /// Why the forced archive could not be written to the override log
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub override_log_error: Option<String>,
+}
+
+impl CommandOutput for ArchiveResult {
+    const COMMAND: &'static str = "archive-change";
+
+    fn to_text(&self) -> String {
+        let mut text = format!(
+            "Change '{}' archived successfully!\n\n{}",
+            self.change_id, self.output
+        );
+        if let Some(error) = &self.override_log_error {
+            text.push_str(&format!(
+                "\n⚠ Archived with {} incomplete task(s) (--force), but the override could not be recorded in .openspec/{}: {}\n{}",
+                self.forced_incomplete_tasks.len(),
+                OVERRIDE_LOG,
+                error,
+                format_listing(&self.forced_incomplete_tasks)
+            ));
+        } else if !self.forced_incomplete_tasks.is_empty() {
+            text.push_str(&format!(
+                "\n⚠ Archived with {} incomplete task(s) (--force). Override recorded in .openspec/{}:\n{}",
+                self.forced_incomplete_tasks.len(),
+                OVERRIDE_LOG,
+                format_listing(&self.forced_incomplete_tasks)
+            ));
+        }
+        text
+    }
+}
 
 /// Handle `openspec:archive-change` command
 /// Archives a completed change
-pub fn handle_archive_change(workspace_path: &Path, change_id: &str) -> Result<String> {
+pub fn handle_archive_change(
+    workspace_path: &Path,
+    change_id: &str,
+    config: &ExtensionConfig,
+    force: bool,
+) -> Result<ArchiveResult> {
     eprintln!("[OpenSpec] Archiving change: {}", change_id);
 
     // Verify change exists
@@ -16,10 +82,41 @@ pub fn handle_archive_change(workspace_path: &Path, change_id: &str) -> Result<S
         ));
     }
 
-    // TODO: Verify all tasks are complete before archiving
-    // This will be implemented when workflow tracking is added
+    archive_with(workspace_path, change_id, config, force, || run_archive(workspace_path, change_id))
+}
+
+/// Check tasks, run `archive`, and record a forced override once it succeeded
+fn archive_with(
+    workspace_path: &Path,
+    change_id: &str,
+    config: &ExtensionConfig,
+    force: bool,
+    archive: impl FnOnce() -> Result<String>,
+) -> Result<ArchiveResult> {
+    // Verify all tasks are complete before shelling out with --yes
+    let forced_incomplete_tasks = preflight(workspace_path, change_id, config, force)?;
+
+    let output = archive()?;
+    // Only an archive that actually happened counts as an override. The change
+    // is already archived by now, so a log failure is a warning, not an error.
+    let override_log_error = if forced_incomplete_tasks.is_empty() {
+        None
+    } else {
+        record_override(workspace_path, change_id, &forced_incomplete_tasks)
+            .err()
+            .map(|e| format!("{:#}", e))
+    };
 
-    // Run openspec archive command
+    Ok(ArchiveResult {
+        change_id: change_id.to_string(),
+        output,
+        forced_incomplete_tasks,
+        override_log_error,
+    })
+}
+
+/// Run `openspec archive` and return its output
+fn run_archive(workspace_path: &Path, change_id: &str) -> Result<String> {
     let output = Command::new("openspec")
         .arg("archive")
         .arg(change_id)
@@ -29,11 +126,7 @@ pub fn handle_archive_change(workspace_path: &Path, change_id: &str) -> Result<S
         .context("Failed to execute openspec archive")?;
 
     if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        Ok(format!(
-            "Change '{}' archived successfully!\n\n{}",
-            change_id, stdout
-        ))
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
         Err(anyhow::anyhow!(
@@ -42,3 +135,164 @@ pub fn handle_archive_change(workspace_path: &Path, change_id: &str) -> Result<S
         ))
     }
 }
+
+/// Refuse to archive while tasks are unchecked, unless forced
+///
+/// Returns the incomplete tasks when the archive is forced past them.
+fn preflight(
+    workspace_path: &Path,
+    change_id: &str,
+    config: &ExtensionConfig,
+    force: bool,
+) -> Result<Vec<PendingTask>> {
+    if !config.workflow.require_all_tasks_complete {
+        return Ok(Vec::new());
+    }
+
+    let tasks_file = workspace_path
+        .join("openspec")
+        .join("changes")
+        .join(change_id)
+        .join("tasks.md");
+    if !tasks_file.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = std::fs::read_to_string(&tasks_file)
+        .context("Failed to read tasks.md")?;
+    let tasks = parse_tasks(&content);
+    let incomplete = tasks.incomplete();
+
+    if incomplete.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pending: Vec<PendingTask> = incomplete.iter().copied().map(PendingTask::from).collect();
+
+    if !force {
+        return Err(anyhow::anyhow!(
+            "Cannot archive '{}': {} of {} task(s) incomplete:\n\n{}\n\n\
+            Complete the tasks, or re-run with --force to archive anyway (the override is recorded).",
+            change_id,
+            incomplete.len(),
+            tasks.total(),
+            format_listing(&pending)
+        ));
+    }
+
+    Ok(pending)
+}
+
+/// `- [ ] 1.2 Tests (tasks.md:4)`, one line per task
+fn format_listing(tasks: &[PendingTask]) -> String {
+    tasks
+        .iter()
+        .map(|t| format!("- [ ] {} (tasks.md:{})", t.label, t.line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Append a forced archive to `.openspec/archive-overrides.jsonl`
+fn record_override(workspace_path: &Path, change_id: &str, incomplete: &[PendingTask]) -> Result<()> {
+    let record = ArchiveOverride {
+        change_id,
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default(),
+        user: std::env::var("USER").or_else(|_| std::env::var("USERNAME")).ok(),
+        incomplete_tasks: incomplete.iter().map(|t| t.label.clone()).collect(),
+    };
+
+    let log_dir = workspace_path.join(".openspec");
+    std::fs::create_dir_all(&log_dir)
+        .context("Failed to create .openspec directory")?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_dir.join(OVERRIDE_LOG))
+        .context("Failed to open archive override log")?;
+    writeln!(file, "{}", serde_json::to_string(&record)?)
+        .context("Failed to record archive override")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn workspace_with_tasks(tasks: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let change_dir = temp_dir.path().join("openspec/changes/add-export");
+        std::fs::create_dir_all(&change_dir).unwrap();
+        std::fs::write(change_dir.join("tasks.md"), tasks).unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_preflight_refuses_incomplete_tasks() {
+        let workspace = workspace_with_tasks("- [x] 1.1 Parser\n- [ ] 1.2 Tests\n");
+        let config = ExtensionConfig::default();
+
+        let err = preflight(workspace.path(), "add-export", &config, false).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("1 of 2 task(s) incomplete"));
+        assert!(message.contains("- [ ] 1.2 Tests (tasks.md:2)"));
+        assert!(!workspace.path().join(".openspec").join(OVERRIDE_LOG).exists());
+    }
+
+    #[test]
+    fn test_forced_archive_records_override_after_success() {
+        let workspace = workspace_with_tasks("- [ ] 1.1 Parser\n");
+        let config = ExtensionConfig::default();
+        let log_path = workspace.path().join(".openspec").join(OVERRIDE_LOG);
+
+        let err = archive_with(workspace.path(), "add-export", &config, true, || {
+            Err(anyhow::anyhow!("Failed to execute openspec archive"))
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("openspec archive"));
+        assert!(!log_path.exists());
+
+        let result = archive_with(workspace.path(), "add-export", &config, true, || Ok("Archived".to_string())).unwrap();
+        assert_eq!(result.forced_incomplete_tasks.len(), 1);
+        assert_eq!(result.forced_incomplete_tasks[0].label, "1.1 Parser");
+
+        let log = std::fs::read_to_string(&log_path).unwrap();
+        let record: serde_json::Value = serde_json::from_str(log.trim()).unwrap();
+        assert_eq!(record["change_id"], "add-export");
+        assert_eq!(record["incomplete_tasks"][0], "1.1 Parser");
+    }
+
+    #[test]
+    fn test_override_log_failure_after_archive_is_a_warning() {
+        let workspace = workspace_with_tasks("- [ ] 1.1 Parser\n");
+        // A file where the log directory should be makes the log unwritable
+        std::fs::write(workspace.path().join(".openspec"), "").unwrap();
+
+        let result = archive_with(workspace.path(), "add-export", &ExtensionConfig::default(), true, || {
+            Ok("Archived".to_string())
+        })
+        .unwrap();
+        let error = result.override_log_error.as_deref().unwrap();
+        assert!(error.contains("Failed to create .openspec directory"));
+        let text = result.to_text();
+        assert!(text.starts_with("Change 'add-export' archived successfully!"));
+        assert!(text.contains("but the override could not be recorded"));
+        assert!(text.contains("- [ ] 1.1 Parser (tasks.md:1)"));
+    }
+
+    #[test]
+    fn test_preflight_passes_when_complete_or_disabled() {
+        let workspace = workspace_with_tasks("- [x] 1.1 Parser\n");
+        let mut config = ExtensionConfig::default();
+        assert!(preflight(workspace.path(), "add-export", &config, false).unwrap().is_empty());
+
+        let workspace = workspace_with_tasks("- [ ] 1.1 Parser\n");
+        config.workflow.require_all_tasks_complete = false;
+        assert!(preflight(workspace.path(), "add-export", &config, false).unwrap().is_empty());
+    }
+}
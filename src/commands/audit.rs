@@ -1,28 +1,72 @@
 use anyhow::Result;
-use std::path::Path;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use super::output::CommandOutput;
+use crate::utils::audit::{audit_dir, load_audit_records};
+
+/// Result of `openspec:view-audit`
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditResult {
+    pub audit_dir: PathBuf,
+    /// Number of readable entries in the audit directory
+    pub entries: usize,
+    pub filter: Option<String>,
+}
+
+impl CommandOutput for AuditResult {
+    const COMMAND: &'static str = "view-audit";
+
+    fn to_text(&self) -> String {
+        if self.entries == 0 {
+            return "No audit entries found. Generate code with 'openspec:apply-change' to create audit records.".to_string();
+        }
+
+        // Placeholder for Phase 1
+        // Full audit implementation will come in Phase 4
+        format!(
+            "Audit trail viewer is not yet implemented.\n\n\
+            This feature will be available in Phase 4 of development.\n\n\
+            {} audit entries are stored in: {:?}\n\n\
+            Features coming:\n\
+            - View all code generation audit entries\n\
+            - Filter by developer, change, LLM provider, date\n\
+            - Export audit data for compliance\n\
+            - Cryptographic signature verification",
+            self.entries,
+            self.audit_dir
+        )
+    }
+}
 
 /// Handle `openspec:view-audit` command
 /// Views the audit trail
-pub fn handle_view_audit(workspace_path: &Path, _filter: Option<&str>) -> Result<String> {
+pub fn handle_view_audit(workspace_path: &Path, filter: Option<&str>) -> Result<AuditResult> {
     eprintln!("[OpenSpec] Viewing audit trail");
 
-    let audit_dir = workspace_path.join(".openspec").join("audit");
+    Ok(AuditResult {
+        entries: load_audit_records(workspace_path)?.len(),
+        audit_dir: audit_dir(workspace_path),
+        filter: filter.map(str::to_string),
+    })
+}
 
-    if !audit_dir.exists() {
-        return Ok("No audit entries found. Generate code with 'openspec:apply-change' to create audit records.".to_string());
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
 
-    // Placeholder for Phase 1
-    // Full audit implementation will come in Phase 4
-    Ok(format!(
-        "Audit trail viewer is not yet implemented.\n\n\
-        This feature will be available in Phase 4 of development.\n\n\
-        Audit entries will be stored in: {:?}\n\n\
-        Features coming:\n\
-        - View all code generation audit entries\n\
-        - Filter by developer, change, LLM provider, date\n\
-        - Export audit data for compliance\n\
-        - Cryptographic signature verification",
-        audit_dir
-    ))
+    #[test]
+    fn test_empty_audit_dir_has_no_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = audit_dir(temp_dir.path());
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let result = handle_view_audit(temp_dir.path(), None).unwrap();
+        assert_eq!(result.entries, 0);
+        assert!(result.to_text().starts_with("No audit entries found."));
+
+        std::fs::write(dir.join("20250114_090000-a.json"), r#"{"id": "a", "change_id": "add-2fa"}"#).unwrap();
+        assert_eq!(handle_view_audit(temp_dir.path(), None).unwrap().entries, 1);
+    }
 }
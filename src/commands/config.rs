@@ -0,0 +1,193 @@
+use anyhow::{Result, Context};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+use super::output::CommandOutput;
+use crate::utils::config::ExtensionConfig;
+use crate::utils::layers::{user_config_path, ConfigEntry, LayeredConfig};
+use crate::utils::migrate::{migrate, MigrationReport};
+use crate::utils::schema::config_schema;
+
+/// Result of `openspec:show-config`
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigResult {
+    pub user_file: Option<String>,
+    pub workspace_file: String,
+    /// Effective values, optionally filtered by key prefix
+    pub entries: Vec<ConfigEntry>,
+}
+
+impl CommandOutput for ConfigResult {
+    const COMMAND: &'static str = "show-config";
+
+    fn to_text(&self) -> String {
+        if self.entries.is_empty() {
+            return "No matching configuration keys.".to_string();
+        }
+        format!(
+            "OpenSpec Configuration:\n\n{}\n\nLayers (lowest precedence first): default, user ({}), workspace ({}), environment (OPENSPEC_*)",
+            self.entries.iter()
+                .map(|e| format!("{} = {} ({})", e.key, e.value, e.source.label()))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            self.user_file.as_deref().unwrap_or("none"),
+            self.workspace_file
+        )
+    }
+}
+
+/// Handle `openspec:show-config` command
+/// Shows effective configuration values and the layer each came from
+pub fn handle_show_config(workspace_path: &Path, prefix: Option<&str>) -> Result<ConfigResult> {
+    eprintln!("[OpenSpec] Showing configuration");
+
+    let layered = LayeredConfig::load(workspace_path)?;
+    let entries = layered
+        .entries()
+        .into_iter()
+        .filter(|e| prefix.is_none_or(|p| e.key.starts_with(p)))
+        .collect();
+
+    Ok(ConfigResult {
+        user_file: user_config_path().map(|p| p.display().to_string()),
+        workspace_file: ExtensionConfig::path(workspace_path).display().to_string(),
+        entries,
+    })
+}
+
+/// Result of `openspec:config-schema`
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaResult {
+    pub schema: Value,
+    /// Workspace-relative path the schema was written to, if requested
+    pub written_to: Option<String>,
+}
+
+impl CommandOutput for SchemaResult {
+    const COMMAND: &'static str = "config-schema";
+
+    fn to_text(&self) -> String {
+        match &self.written_to {
+            Some(path) => format!(
+                "JSON Schema written to {}\n\nReference it from .openspec-config.json with:\n  \"$schema\": \"./{}\"",
+                path, path
+            ),
+            None => serde_json::to_string_pretty(&self.schema).unwrap_or_default(),
+        }
+    }
+}
+
+/// Handle `openspec:config-schema` command
+/// Emits a JSON Schema for `.openspec-config.json`, optionally writing it to a file
+pub fn handle_config_schema(workspace_path: &Path, output: Option<&str>) -> Result<SchemaResult> {
+    eprintln!("[OpenSpec] Exporting configuration schema");
+
+    let schema = config_schema();
+    if let Some(path) = output {
+        std::fs::write(workspace_path.join(path), serde_json::to_string_pretty(&schema)? + "\n")
+            .with_context(|| format!("Failed to write {}", path))?;
+    }
+
+    Ok(SchemaResult {
+        schema,
+        written_to: output.map(str::to_string),
+    })
+}
+
+/// Result of `openspec:migrate-config`
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrateResult {
+    pub file: String,
+    /// `None` when the workspace has no config file
+    pub report: Option<MigrationReport>,
+    /// Whether the migrated configuration was saved
+    pub written: bool,
+}
+
+impl CommandOutput for MigrateResult {
+    const COMMAND: &'static str = "migrate-config";
+
+    fn to_text(&self) -> String {
+        let Some(report) = &self.report else {
+            return format!("No configuration file found at {}. Nothing to migrate.", self.file);
+        };
+        if report.is_current() {
+            return format!("{} is already at config version {}.", self.file, report.to_version);
+        }
+
+        let changes = report.changes.iter()
+            .map(|c| format!("- {}", c))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let footer = if self.written {
+            format!("Saved {}.", self.file)
+        } else {
+            "Re-run with --write to save the migrated file.".to_string()
+        };
+        format!(
+            "Migrated {} from config version {} to {}:\n{}\n\n{}",
+            self.file, report.from_version, report.to_version, changes, footer
+        )
+    }
+}
+
+/// Handle `openspec:migrate-config` command
+/// Upgrades the workspace config file to the current version, saving it with `--write`
+pub fn handle_migrate_config(workspace_path: &Path, write: bool) -> Result<MigrateResult> {
+    eprintln!("[OpenSpec] Migrating configuration");
+
+    let path = ExtensionConfig::path(workspace_path);
+    let file = path.display().to_string();
+    if !path.exists() {
+        return Ok(MigrateResult { file, report: None, written: false });
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", file))?;
+    let mut document: Value = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid JSON in {}", file))?;
+    let report = migrate(&mut document)?;
+
+    let written = write && !report.is_current();
+    if written {
+        // Saving keeps only what the file already set, so defaults don't get pinned
+        ExtensionConfig::from_overrides(document)
+            .with_context(|| format!("Invalid configuration in {}", file))?
+            .save(&path)?;
+    }
+
+    Ok(MigrateResult { file, report: Some(report), written })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_migrate_config_writes_only_when_asked() {
+        let workspace = TempDir::new().unwrap();
+        let path = ExtensionConfig::path(workspace.path());
+        std::fs::write(&path, r#"{"team": "payments", "audit": {"retention_days": 30}}"#).unwrap();
+
+        let result = handle_migrate_config(workspace.path(), false).unwrap();
+        assert_eq!(result.report.as_ref().unwrap().from_version, 0);
+        assert!(!result.written);
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("version"));
+
+        let result = handle_migrate_config(workspace.path(), true).unwrap();
+        assert!(result.written);
+        let saved: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved["version"], crate::utils::migrate::CONFIG_VERSION);
+        assert_eq!(saved["team"], "payments");
+        assert_eq!(saved["audit"]["retention_days"], 30);
+        assert_eq!(saved["audit"].as_object().unwrap().len(), 1);
+        assert!(saved.get("llm").is_none());
+        assert!(saved.get("validation").is_none());
+
+        let result = handle_migrate_config(workspace.path(), true).unwrap();
+        assert!(result.report.unwrap().is_current());
+        assert!(!result.written);
+    }
+}
@@ -1,22 +1,29 @@
 use anyhow::Result;
+use serde::Serialize;
 use std::path::Path;
 
+use super::output::CommandOutput;
 use crate::utils::config::ExtensionConfig;
 
-/// Handle `openspec:show-coverage` command
-/// Shows spec coverage analysis
-pub fn handle_show_coverage(workspace_path: &Path, _config: &ExtensionConfig) -> Result<String> {
-    eprintln!("[OpenSpec] Showing coverage analysis");
+/// Result of `openspec:show-coverage`
+#[derive(Debug, Clone, Serialize)]
+pub struct CoverageResult {
+    /// Whether `openspec/` exists
+    pub initialized: bool,
+    /// Coverage percentage; `None` until coverage analysis lands
+    pub coverage_percent: Option<f64>,
+}
 
-    let openspec_dir = workspace_path.join("openspec");
+impl CommandOutput for CoverageResult {
+    const COMMAND: &'static str = "show-coverage";
 
-    if !openspec_dir.exists() {
-        return Ok("OpenSpec not initialized. Run 'openspec:init' first.".to_string());
-    }
+    fn to_text(&self) -> String {
+        if !self.initialized {
+            return "OpenSpec not initialized. Run 'openspec:init' first.".to_string();
+        }
 
-    // Placeholder for Phase 1
-    // Full coverage analysis will come in Phase 6
-    Ok(format!(
+        // Placeholder for Phase 1
+        // Full coverage analysis will come in Phase 6
         "Coverage analysis is not yet implemented.\n\n\
         This feature will be available in Phase 6 of development.\n\n\
         Features coming:\n\
@@ -25,6 +32,17 @@ pub fn handle_show_coverage(workspace_path: &Path, _config: &ExtensionConfig) ->
         - Line-level spec→code mapping\n\
         - Visual coverage heat maps\n\
         - Coverage trends over time\n\n\
-        Coverage will be calculated from audit trail data."
-    ))
+        Coverage will be calculated from audit trail data.".to_string()
+    }
+}
+
+/// Handle `openspec:show-coverage` command
+/// Shows spec coverage analysis
+pub fn handle_show_coverage(workspace_path: &Path, _config: &ExtensionConfig) -> Result<CoverageResult> {
+    eprintln!("[OpenSpec] Showing coverage analysis");
+
+    Ok(CoverageResult {
+        initialized: workspace_path.join("openspec").exists(),
+        coverage_percent: None,
+    })
 }
@@ -1,10 +1,29 @@
 use anyhow::{Result, Context};
+use serde::Serialize;
 use std::path::Path;
 use std::process::Command;
 
+use super::output::CommandOutput;
+
+/// Result of `openspec:init`
+#[derive(Debug, Clone, Serialize)]
+pub struct InitResult {
+    pub cli_version: String,
+    /// Output of `openspec init`
+    pub output: String,
+}
+
+impl CommandOutput for InitResult {
+    const COMMAND: &'static str = "init";
+
+    fn to_text(&self) -> String {
+        format!("OpenSpec initialized successfully!\n\n{}", self.output)
+    }
+}
+
 /// Handle `openspec:init` command
 /// Initializes OpenSpec in the current workspace
-pub fn handle_init(workspace_path: &Path) -> Result<String> {
+pub fn handle_init(workspace_path: &Path) -> Result<InitResult> {
     eprintln!("[OpenSpec] Initializing OpenSpec in: {:?}", workspace_path);
 
     // Check if OpenSpec CLI is installed
@@ -12,10 +31,11 @@ pub fn handle_init(workspace_path: &Path) -> Result<String> {
         .arg("--version")
         .output();
 
-    match version_check {
+    let cli_version = match version_check {
         Ok(output) if output.status.success() => {
-            let version = String::from_utf8_lossy(&output.stdout);
-            eprintln!("[OpenSpec] Found OpenSpec CLI: {}", version.trim());
+            let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            eprintln!("[OpenSpec] Found OpenSpec CLI: {}", version);
+            version
         }
         _ => {
             return Err(anyhow::anyhow!(
@@ -23,7 +43,7 @@ pub fn handle_init(workspace_path: &Path) -> Result<String> {
                 npm install -g @fission-ai/openspec@latest"
             ));
         }
-    }
+    };
 
     // Run openspec init
     let output = Command::new("openspec")
@@ -33,11 +53,10 @@ pub fn handle_init(workspace_path: &Path) -> Result<String> {
         .context("Failed to execute openspec init")?;
 
     if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        Ok(format!(
-            "OpenSpec initialized successfully!\n\n{}",
-            stdout
-        ))
+        Ok(InitResult {
+            cli_version,
+            output: String::from_utf8_lossy(&output.stdout).into_owned(),
+        })
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
         Err(anyhow::anyhow!(
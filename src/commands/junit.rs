@@ -0,0 +1,209 @@
+//! JUnit XML rendering of validation results, for CI test-report views
+//!
+//! Each validated file is a test suite. Requirements, and optionally tasks
+//! in `tasks.md`, are test cases; a case fails when it has error-level
+//! findings. Findings outside any requirement or reported task go to a
+//! "File checks" case.
+
+use super::validate::{format_diagnostic, CheckedItem, FileReport};
+use crate::validation::{Diagnostic, Severity};
+
+/// Test case holding findings that are not inside a requirement or task
+const FILE_CASE: &str = "File checks";
+
+struct TestCase<'a> {
+    name: String,
+    errors: Vec<&'a Diagnostic>,
+    /// Warnings and lower, reported as case output
+    notes: Vec<&'a Diagnostic>,
+    /// Unchecked task without errors
+    skipped: bool,
+}
+
+impl<'a> TestCase<'a> {
+    fn new(name: String) -> Self {
+        Self { name, errors: Vec::new(), notes: Vec::new(), skipped: false }
+    }
+
+    fn add(&mut self, diagnostic: &'a Diagnostic) {
+        if diagnostic.severity == Severity::Error {
+            self.errors.push(diagnostic);
+        } else {
+            self.notes.push(diagnostic);
+        }
+    }
+}
+
+/// Render validation reports as a JUnit XML document
+///
+/// Without `include_tasks`, tasks get no cases of their own and their
+/// findings count towards "File checks".
+pub fn junit_report(reports: &[&FileReport], include_tasks: bool) -> String {
+    let suites: Vec<(&str, Vec<TestCase>)> = reports
+        .iter()
+        .map(|report| (report.file.as_str(), test_cases(report, include_tasks)))
+        .collect();
+
+    let count = |f: &dyn Fn(&TestCase) -> bool| -> usize {
+        suites.iter().flat_map(|(_, cases)| cases).filter(|c| f(c)).count()
+    };
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"openspec\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+        count(&|_| true),
+        count(&|c| !c.errors.is_empty()),
+        count(&|c| c.skipped)
+    ));
+
+    for (file, cases) in &suites {
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+            escape(file),
+            cases.len(),
+            cases.iter().filter(|c| !c.errors.is_empty()).count(),
+            cases.iter().filter(|c| c.skipped).count()
+        ));
+        for case in cases {
+            write_case(&mut xml, file, case);
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// One case for file-level findings, then one per requirement or task
+fn test_cases(report: &FileReport, include_tasks: bool) -> Vec<TestCase<'_>> {
+    let items: Vec<&CheckedItem> = report
+        .items
+        .iter()
+        .filter(|item| include_tasks || item.completed.is_none())
+        .collect();
+    let mut file_case = TestCase::new(FILE_CASE.to_string());
+    let mut cases: Vec<TestCase> = items.iter().map(|item| TestCase::new(case_name(item))).collect();
+
+    for diagnostic in &report.diagnostics {
+        match items.iter().position(|item| item.span.contains(diagnostic.span.start)) {
+            Some(i) => cases[i].add(diagnostic),
+            None => file_case.add(diagnostic),
+        }
+    }
+    for (case, item) in cases.iter_mut().zip(&items) {
+        case.skipped = item.completed == Some(false) && case.errors.is_empty();
+    }
+
+    cases.insert(0, file_case);
+    cases
+}
+
+fn case_name(item: &CheckedItem) -> String {
+    match item.completed {
+        Some(_) => format!("Task: {}", item.name),
+        None => format!("Requirement: {}", item.name),
+    }
+}
+
+fn write_case(xml: &mut String, file: &str, case: &TestCase) {
+    let open = format!(
+        "    <testcase name=\"{}\" classname=\"{}\"",
+        escape(&case.name),
+        escape(file)
+    );
+    if case.errors.is_empty() && case.notes.is_empty() && !case.skipped {
+        xml.push_str(&open);
+        xml.push_str("/>\n");
+        return;
+    }
+
+    xml.push_str(&open);
+    xml.push_str(">\n");
+    if let Some(first) = case.errors.first() {
+        xml.push_str(&format!(
+            "      <failure message=\"{}\" type=\"{}\">{}</failure>\n",
+            escape(&first.message),
+            escape(&first.rule_id),
+            escape(&listing(&case.errors))
+        ));
+    } else if case.skipped {
+        xml.push_str("      <skipped message=\"Task not completed\"/>\n");
+    }
+    if !case.notes.is_empty() {
+        xml.push_str(&format!("      <system-out>{}</system-out>\n", escape(&listing(&case.notes))));
+    }
+    xml.push_str("    </testcase>\n");
+}
+
+fn listing(diagnostics: &[&Diagnostic]) -> String {
+    diagnostics.iter().map(|d| format_diagnostic(d)).collect::<Vec<_>>().join("\n")
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::config::ExtensionConfig;
+    use crate::validation::Validator;
+
+    #[test]
+    fn test_junit_report() {
+        let rules = ExtensionConfig::default().validation.rules;
+        let validator = Validator::new(&rules);
+        let spec = FileReport::check(
+            &validator,
+            "openspec/specs/auth/spec.md",
+            "# Auth\n\n### Requirement: Login\nThe system SHALL log users in.\n\n\
+             #### Scenario: Success\n- **WHEN** valid\n- **THEN** a token is issued\n\n\
+             ### Requirement: Logout <all>\nUsers log out.\n",
+        );
+        let tasks = FileReport::check(
+            &validator,
+            "openspec/changes/add-2fa/tasks.md",
+            "## 1. Setup\n- [x] 1.1 Add setting\n- [ ] 1.2 Add UI\n",
+        );
+        let xml = junit_report(&[&spec, &tasks], true);
+
+        assert!(xml.contains("<testsuites name=\"openspec\" tests=\"6\" failures=\"1\" skipped=\"1\">"));
+        assert!(xml.contains(
+            "<testsuite name=\"openspec/specs/auth/spec.md\" tests=\"3\" failures=\"1\" skipped=\"0\">"
+        ));
+        assert!(xml.contains("<testcase name=\"Requirement: Login\" classname=\"openspec/specs/auth/spec.md\"/>"));
+        assert!(xml.contains("<testcase name=\"Requirement: Logout &lt;all&gt;\""));
+        assert!(xml.contains("<failure message=\"Requirement &apos;Logout &lt;all&gt;&apos; does not use SHALL or MUST\" type=\"OS005\">"));
+        assert!(xml.contains("<testcase name=\"Task: 1.1 Add setting\" classname=\"openspec/changes/add-2fa/tasks.md\"/>"));
+        assert!(xml.contains("<testcase name=\"Task: 1.2 Add UI\" classname=\"openspec/changes/add-2fa/tasks.md\">\n      <skipped message=\"Task not completed\"/>"));
+    }
+
+    #[test]
+    fn test_junit_report_without_tasks() {
+        let rules = ExtensionConfig::default().validation.rules;
+        let validator = Validator::new(&rules);
+        let tasks = FileReport::check(
+            &validator,
+            "openspec/changes/add-2fa/tasks.md",
+            "## 1. Setup\n- [x] 1.1 Add setting\n- [ ] 1.2 Add UI\n- [ ] 1.2 Duplicate\n",
+        );
+        assert!(tasks.errors + tasks.warnings > 0);
+
+        let xml = junit_report(&[&tasks], false);
+        assert!(xml.contains("<testsuite name=\"openspec/changes/add-2fa/tasks.md\" tests=\"1\""));
+        assert!(!xml.contains("Task: "));
+        assert!(!xml.contains("<skipped"));
+        // Task findings still surface, under the file case
+        assert!(xml.contains("<testcase name=\"File checks\" classname=\"openspec/changes/add-2fa/tasks.md\">"));
+    }
+}
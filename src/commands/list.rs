@@ -1,16 +1,79 @@
 use anyhow::{Result, Context};
+use serde::Serialize;
 use std::path::Path;
 use std::fs;
 
+use super::output::CommandOutput;
+use crate::spec::change::{format_age, ChangeSummary};
+
+/// Sort order for `openspec:list-changes --sort <order>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeSort {
+    /// Alphabetical by change ID
+    Name,
+    /// Proposed, then In Progress, then Complete
+    Status,
+    /// Highest task completion first
+    Progress,
+    /// Most recently modified first
+    Modified,
+}
+
+impl ChangeSort {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "name" => Ok(Self::Name),
+            "status" => Ok(Self::Status),
+            "progress" => Ok(Self::Progress),
+            "modified" => Ok(Self::Modified),
+            _ => Err(anyhow::anyhow!(
+                "Unknown sort order '{}'. Use one of: name, status, progress, modified",
+                value
+            )),
+        }
+    }
+}
+
+/// Result of `openspec:list-changes`
+#[derive(Debug, Clone, Serialize)]
+pub struct ListChangesResult {
+    /// Whether `openspec/changes` exists
+    pub initialized: bool,
+    /// Changes in the requested sort order
+    pub changes: Vec<ChangeSummary>,
+}
+
+impl CommandOutput for ListChangesResult {
+    const COMMAND: &'static str = "list-changes";
+
+    fn to_text(&self) -> String {
+        if !self.initialized {
+            return "No changes found. OpenSpec may not be initialized or no proposals created yet.".to_string();
+        }
+        if self.changes.is_empty() {
+            return "No changes found. Create one with 'openspec:new-proposal'".to_string();
+        }
+        format!(
+            "OpenSpec Changes ({}):\n\n{}\n\nUse 'openspec:apply-change <name>' to generate code for a change.",
+            self.changes.len(),
+            self.changes.iter()
+                .enumerate()
+                .map(|(i, change)| format!("{}. {}", i + 1, format_change(change)))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    }
+}
+
 /// Handle `openspec:list-changes` command
 /// Lists all OpenSpec changes
-pub fn handle_list_changes(workspace_path: &Path) -> Result<String> {
+pub fn handle_list_changes(workspace_path: &Path, sort: ChangeSort) -> Result<ListChangesResult> {
     eprintln!("[OpenSpec] Listing changes");
 
     let changes_dir = workspace_path.join("openspec").join("changes");
 
     if !changes_dir.exists() {
-        return Ok("No changes found. OpenSpec may not be initialized or no proposals created yet.".to_string());
+        return Ok(ListChangesResult { initialized: false, changes: Vec::new() });
     }
 
     let mut changes = Vec::new();
@@ -20,28 +83,99 @@ pub fn handle_list_changes(workspace_path: &Path) -> Result<String> {
         let path = entry.path();
 
         if path.is_dir() {
-            if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                // Check if proposal.md exists to confirm it's a valid change
-                let proposal_file = path.join("proposal.md");
-                if proposal_file.exists() {
-                    changes.push(name.to_string());
-                }
+            // Check if proposal.md exists to confirm it's a valid change
+            let proposal_file = path.join("proposal.md");
+            if proposal_file.exists() {
+                changes.push(ChangeSummary::load(&path)?);
             }
         }
     }
 
-    if changes.is_empty() {
-        Ok("No changes found. Create one with 'openspec:new-proposal'".to_string())
+    sort_changes(&mut changes, sort);
+    Ok(ListChangesResult { initialized: true, changes })
+}
+
+fn sort_changes(changes: &mut [ChangeSummary], sort: ChangeSort) {
+    changes.sort_by(|a, b| a.id.cmp(&b.id));
+    match sort {
+        ChangeSort::Name => {}
+        ChangeSort::Status => changes.sort_by_key(|c| c.status),
+        ChangeSort::Progress => changes.sort_by(|a, b| b.progress().total_cmp(&a.progress())),
+        ChangeSort::Modified => changes.sort_by_key(|c| std::cmp::Reverse(c.last_modified)),
+    }
+}
+
+/// `add-auth [In Progress] 7/12 tasks, 2 spec delta(s), modified 3 days ago`
+fn format_change(change: &ChangeSummary) -> String {
+    let tasks = if change.total_tasks == 0 {
+        "no tasks".to_string()
     } else {
-        changes.sort();
-        Ok(format!(
-            "OpenSpec Changes ({}):\n\n{}\n\nUse 'openspec:apply-change <name>' to generate code for a change.",
-            changes.len(),
-            changes.iter()
-                .enumerate()
-                .map(|(i, name)| format!("{}. {}", i + 1, name))
-                .collect::<Vec<_>>()
-                .join("\n")
-        ))
+        format!("{}/{} tasks", change.completed_tasks, change.total_tasks)
+    };
+    let modified = change
+        .last_modified
+        .map(|t| format!(", modified {}", format_age(t)))
+        .unwrap_or_default();
+
+    format!(
+        "{} [{}] {}, {} spec delta(s){}",
+        change.id,
+        change.status.label(),
+        tasks,
+        change.spec_deltas,
+        modified
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::change::ChangeStatus;
+
+    fn change(id: &str, completed: usize, total: usize, modified: u64) -> ChangeSummary {
+        ChangeSummary {
+            id: id.to_string(),
+            status: ChangeStatus::infer(completed, total),
+            completed_tasks: completed,
+            total_tasks: total,
+            spec_deltas: 1,
+            last_modified: Some(modified),
+        }
+    }
+
+    fn ids(changes: &[ChangeSummary]) -> Vec<&str> {
+        changes.iter().map(|c| c.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_sort_orders() {
+        let mut changes = vec![
+            change("b-done", 4, 4, 100),
+            change("a-started", 1, 4, 300),
+            change("c-new", 0, 4, 200),
+        ];
+
+        sort_changes(&mut changes, ChangeSort::Name);
+        assert_eq!(ids(&changes), vec!["a-started", "b-done", "c-new"]);
+
+        sort_changes(&mut changes, ChangeSort::Status);
+        assert_eq!(ids(&changes), vec!["c-new", "a-started", "b-done"]);
+
+        sort_changes(&mut changes, ChangeSort::Progress);
+        assert_eq!(ids(&changes), vec!["b-done", "a-started", "c-new"]);
+
+        sort_changes(&mut changes, ChangeSort::Modified);
+        assert_eq!(ids(&changes), vec!["a-started", "c-new", "b-done"]);
+
+        assert!(ChangeSort::parse("size").is_err());
+    }
+
+    #[test]
+    fn test_format_change() {
+        let mut summary = change("add-auth", 7, 12, 0);
+        summary.spec_deltas = 2;
+        summary.last_modified = None;
+
+        assert_eq!(format_change(&summary), "add-auth [In Progress] 7/12 tasks, 2 spec delta(s)");
     }
 }
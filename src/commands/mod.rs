@@ -5,13 +5,17 @@ pub mod archive;
 pub mod list;
 pub mod audit;
 pub mod validate;
+pub mod validate_all;
 pub mod coverage;
+pub mod config;
+pub mod junit;
+pub mod output;
 
 use zed_extension_api as zed;
-use anyhow::Result;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::utils::config::ExtensionConfig;
+use output::{render, render_error, CommandOutput, OutputFormat};
 
 /// Command handler for all OpenSpec operations
 pub struct CommandHandler {
@@ -31,66 +35,149 @@ impl CommandHandler {
         worktree: &zed::Worktree,
     ) -> Result<String, String> {
         let workspace_path = PathBuf::from(worktree.root_path());
+        self.execute(command, args, &workspace_path)
+    }
+
+    /// Run a command against a workspace
+    ///
+    /// `--format json` (or `--json`) anywhere in `args` renders the result,
+    /// or the error, as a versioned JSON envelope instead of text.
+    pub fn execute(
+        &self,
+        command: &str,
+        mut args: Vec<String>,
+        workspace_path: &Path,
+    ) -> Result<String, String> {
+        let name = command.strip_prefix("openspec:").unwrap_or(command);
+        let mut format = OutputFormat::extract(&mut args)
+            .map_err(|e| render_error(name, &e.to_string(), OutputFormat::Text))?;
+        if let OutputFormat::Junit { include_tasks } = &mut format {
+            *include_tasks = self.config.junit.include_tasks;
+        }
+
+        self.dispatch(command, &args, workspace_path, format)
+            .map_err(|failure| match failure {
+                Failure::Message(message) => render_error(name, &message, format),
+                Failure::Report(report) => report,
+            })
+    }
 
+    fn dispatch(
+        &self,
+        command: &str,
+        args: &[String],
+        workspace_path: &Path,
+        format: OutputFormat,
+    ) -> Result<String, Failure> {
         match command {
             "openspec:init" => {
-                init::handle_init(&workspace_path)
-                    .map_err(|e| e.to_string())
+                emit(init::handle_init(workspace_path), format)
             }
 
             "openspec:new-proposal" => {
-                let name = args.get(0)
-                    .ok_or("Proposal name required")?
-                    .clone();
-                proposal::handle_new_proposal(&workspace_path, &name)
-                    .map_err(|e| e.to_string())
+                let name = args.first()
+                    .ok_or("Proposal name required")?;
+                emit(proposal::handle_new_proposal(workspace_path, name), format)
             }
 
             "openspec:apply-change" => {
-                let change_id = args.get(0)
-                    .ok_or("Change ID required")?
-                    .clone();
+                let change_id = args.first()
+                    .ok_or("Change ID required")?;
                 let llm_provider = args.get(1)
                     .map(|s| s.as_str())
                     .unwrap_or(&self.config.llm.default_provider);
 
-                apply::handle_apply_change(&workspace_path, &change_id, llm_provider, &self.config)
-                    .map_err(|e| e.to_string())
+                emit(apply::handle_apply_change(workspace_path, change_id, llm_provider, &self.config), format)
             }
 
             "openspec:archive-change" => {
-                let change_id = args.get(0)
-                    .ok_or("Change ID required")?
-                    .clone();
-                archive::handle_archive_change(&workspace_path, &change_id)
-                    .map_err(|e| e.to_string())
+                let change_id = args.first()
+                    .ok_or("Change ID required")?;
+                let force = args.iter().skip(1).any(|a| a == "--force");
+                emit(archive::handle_archive_change(workspace_path, change_id, &self.config, force), format)
             }
 
             "openspec:list-changes" => {
-                list::handle_list_changes(&workspace_path)
-                    .map_err(|e| e.to_string())
+                let sort = match args.iter().position(|a| a == "--sort") {
+                    Some(i) => {
+                        let order = args.get(i + 1).ok_or("Sort order required after --sort")?;
+                        list::ChangeSort::parse(order).map_err(|e| e.to_string())?
+                    }
+                    None => list::ChangeSort::Name,
+                };
+                emit(list::handle_list_changes(workspace_path, sort), format)
             }
 
             "openspec:view-audit" => {
-                let filter = args.get(0).map(|s| s.as_str());
-                audit::handle_view_audit(&workspace_path, filter)
-                    .map_err(|e| e.to_string())
+                let filter = args.first().map(|s| s.as_str());
+                emit(audit::handle_view_audit(workspace_path, filter), format)
             }
 
             "openspec:validate-file" => {
-                let file_path = args.get(0)
-                    .ok_or("File path required")?
-                    .clone();
-                validate::handle_validate_file(&workspace_path, &file_path)
-                    .map_err(|e| e.to_string())
+                let file_path = args.first()
+                    .ok_or("File path required")?;
+                emit(validate::handle_validate_file(workspace_path, file_path, &self.config), format)
+            }
+
+            "openspec:validate-all" => {
+                let result = validate_all::handle_validate_all(workspace_path, &self.config)
+                    .map_err(|e| e.to_string())?;
+                let output = render(&result, format).map_err(|e| e.to_string())?;
+                // Errors fail the command so it can gate merges; the report is still shown
+                if result.passed() {
+                    Ok(output)
+                } else {
+                    Err(Failure::Report(output))
+                }
             }
 
             "openspec:show-coverage" => {
-                coverage::handle_show_coverage(&workspace_path, &self.config)
-                    .map_err(|e| e.to_string())
+                emit(coverage::handle_show_coverage(workspace_path, &self.config), format)
+            }
+
+            "openspec:show-config" => {
+                let prefix = args.first().map(|s| s.as_str());
+                emit(config::handle_show_config(workspace_path, prefix), format)
             }
 
-            _ => Err(format!("Unknown command: {}", command)),
+            "openspec:config-schema" => {
+                let output = args.first().map(|s| s.as_str());
+                emit(config::handle_config_schema(workspace_path, output), format)
+            }
+
+            "openspec:migrate-config" => {
+                let write = args.iter().any(|a| a == "--write");
+                emit(config::handle_migrate_config(workspace_path, write), format)
+            }
+
+            _ => Err(format!("Unknown command: {}", command).into()),
         }
     }
 }
+
+/// Why a command did not succeed
+enum Failure {
+    /// Plain message, rendered as an error in the requested format
+    Message(String),
+    /// Already rendered result of a check that found problems
+    Report(String),
+}
+
+impl From<&str> for Failure {
+    fn from(message: &str) -> Self {
+        Self::Message(message.to_string())
+    }
+}
+
+impl From<String> for Failure {
+    fn from(message: String) -> Self {
+        Self::Message(message)
+    }
+}
+
+/// Render a handler result, leaving errors as plain messages
+fn emit<T: CommandOutput>(result: anyhow::Result<T>, format: OutputFormat) -> Result<String, Failure> {
+    result
+        .and_then(|output| render(&output, format))
+        .map_err(|e| Failure::Message(e.to_string()))
+}
@@ -0,0 +1,195 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::spec::tasks::Task;
+use super::junit::junit_report;
+use super::validate::FileReport;
+use crate::validation::sarif::sarif_log;
+
+/// Version of the JSON output envelope; bump on breaking changes to any result
+pub const OUTPUT_SCHEMA_VERSION: u32 = 1;
+
+/// How command results are rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text
+    Text,
+    /// Versioned JSON envelope
+    Json,
+    /// SARIF 2.1.0 log, for validation commands only
+    Sarif,
+    /// JUnit XML report, for validation commands only
+    Junit {
+        /// Emit a test case per task in `tasks.md`, from `junit.include_tasks`
+        include_tasks: bool,
+    },
+}
+
+impl OutputFormat {
+    /// Remove `--format <text|json|sarif|junit>` or `--json` from command args
+    pub fn extract(args: &mut Vec<String>) -> Result<Self> {
+        if let Some(i) = args.iter().position(|a| a == "--json") {
+            args.remove(i);
+            return Ok(Self::Json);
+        }
+        let Some(i) = args.iter().position(|a| a == "--format") else {
+            return Ok(Self::Text);
+        };
+        args.remove(i);
+        if i >= args.len() {
+            return Err(anyhow::anyhow!("Output format required after --format"));
+        }
+        match args.remove(i).as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            "junit" => Ok(Self::Junit { include_tasks: true }),
+            other => Err(anyhow::anyhow!(
+                "Unknown output format '{}'. Use 'text', 'json', 'sarif' or 'junit'",
+                other
+            )),
+        }
+    }
+}
+
+/// Typed result of a command handler
+pub trait CommandOutput: Serialize {
+    /// Command name used in the JSON envelope, e.g. `list-changes`
+    const COMMAND: &'static str;
+
+    /// Human-readable rendering shown in the editor
+    fn to_text(&self) -> String;
+
+    /// Per-file validation outcomes, for commands that produce them
+    fn reports(&self) -> Option<Vec<&FileReport>> {
+        None
+    }
+}
+
+/// Unchecked task, as listed by `apply-change` and `archive-change`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PendingTask {
+    pub id: Option<String>,
+    pub label: String,
+    /// One-based line in `tasks.md`
+    pub line: usize,
+}
+
+impl From<&Task> for PendingTask {
+    fn from(task: &Task) -> Self {
+        Self {
+            id: task.id.clone(),
+            label: task.label(),
+            line: task.span.line_number(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Envelope<'a, T: Serialize> {
+    schema_version: u32,
+    command: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<&'a T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+}
+
+/// Render a command result in the requested format
+pub fn render<T: CommandOutput>(output: &T, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Text => Ok(output.to_text()),
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(&Envelope {
+            schema_version: OUTPUT_SCHEMA_VERSION,
+            command: T::COMMAND,
+            result: Some(output),
+            error: None,
+        })?),
+        OutputFormat::Sarif => {
+            let reports = output.reports().ok_or_else(|| {
+                anyhow::anyhow!("SARIF output is not available for {}", T::COMMAND)
+            })?;
+            let findings: Vec<_> = reports.iter().flat_map(|r| &r.diagnostics).collect();
+            Ok(serde_json::to_string_pretty(&sarif_log(&findings))?)
+        }
+        OutputFormat::Junit { include_tasks } => {
+            let reports = output.reports().ok_or_else(|| {
+                anyhow::anyhow!("JUnit output is not available for {}", T::COMMAND)
+            })?;
+            Ok(junit_report(&reports, include_tasks))
+        }
+    }
+}
+
+/// Render a command failure in the requested format
+pub fn render_error(command: &str, message: &str, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Text | OutputFormat::Sarif | OutputFormat::Junit { .. } => message.to_string(),
+        OutputFormat::Json => serde_json::to_string_pretty(&Envelope::<()> {
+            schema_version: OUTPUT_SCHEMA_VERSION,
+            command,
+            result: None,
+            error: Some(message),
+        })
+        .unwrap_or_else(|_| message.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Greeting {
+        name: String,
+    }
+
+    impl CommandOutput for Greeting {
+        const COMMAND: &'static str = "greet";
+
+        fn to_text(&self) -> String {
+            format!("Hello, {}!", self.name)
+        }
+    }
+
+    #[test]
+    fn test_extract_format() {
+        let mut args = vec!["add-auth".to_string(), "--format".to_string(), "json".to_string()];
+        assert_eq!(OutputFormat::extract(&mut args).unwrap(), OutputFormat::Json);
+        assert_eq!(args, vec!["add-auth"]);
+
+        let mut args = vec!["--json".to_string()];
+        assert_eq!(OutputFormat::extract(&mut args).unwrap(), OutputFormat::Json);
+        assert!(args.is_empty());
+
+        let mut args = vec!["add-auth".to_string()];
+        assert_eq!(OutputFormat::extract(&mut args).unwrap(), OutputFormat::Text);
+
+        let mut args = vec!["--format".to_string(), "sarif".to_string()];
+        assert_eq!(OutputFormat::extract(&mut args).unwrap(), OutputFormat::Sarif);
+
+        assert!(OutputFormat::extract(&mut vec!["--format".to_string(), "xml".to_string()]).is_err());
+        assert!(OutputFormat::extract(&mut vec!["--format".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_render_envelope() {
+        let greeting = Greeting { name: "spec".to_string() };
+        assert_eq!(render(&greeting, OutputFormat::Text).unwrap(), "Hello, spec!");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&greeting, OutputFormat::Json).unwrap()).unwrap();
+        assert_eq!(json["schema_version"], OUTPUT_SCHEMA_VERSION);
+        assert_eq!(json["command"], "greet");
+        assert_eq!(json["result"]["name"], "spec");
+        assert!(json.get("error").is_none());
+
+        let error: serde_json::Value =
+            serde_json::from_str(&render_error("greet", "boom", OutputFormat::Json)).unwrap();
+        assert_eq!(error["error"], "boom");
+        assert!(error.get("result").is_none());
+
+        let err = render(&greeting, OutputFormat::Sarif).unwrap_err();
+        assert_eq!(err.to_string(), "SARIF output is not available for greet");
+    }
+}
@@ -1,10 +1,38 @@
 use anyhow::{Result, Context};
+use serde::Serialize;
 use std::path::Path;
 use std::process::Command;
 
+use super::output::CommandOutput;
+
+/// Result of `openspec:new-proposal`
+#[derive(Debug, Clone, Serialize)]
+pub struct ProposalResult {
+    pub name: String,
+    /// Directory of the new change, relative to the workspace
+    pub change_dir: String,
+    /// Output of `openspec proposal`
+    pub output: String,
+}
+
+impl CommandOutput for ProposalResult {
+    const COMMAND: &'static str = "new-proposal";
+
+    fn to_text(&self) -> String {
+        format!(
+            "Proposal '{}' created successfully!\n\n{}\n\nNext steps:\n\
+            1. Edit {}/proposal.md\n\
+            2. Add specs to {}/specs/\n\
+            3. Define tasks in {}/tasks.md\n\
+            4. Run 'openspec:apply-change' to generate code",
+            self.name, self.output, self.change_dir, self.change_dir, self.change_dir
+        )
+    }
+}
+
 /// Handle `openspec:new-proposal` command
 /// Creates a new OpenSpec change proposal
-pub fn handle_new_proposal(workspace_path: &Path, name: &str) -> Result<String> {
+pub fn handle_new_proposal(workspace_path: &Path, name: &str) -> Result<ProposalResult> {
     eprintln!("[OpenSpec] Creating new proposal: {}", name);
 
     // Validate proposal name
@@ -31,15 +59,11 @@ pub fn handle_new_proposal(workspace_path: &Path, name: &str) -> Result<String>
         .context("Failed to execute openspec proposal")?;
 
     if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        Ok(format!(
-            "Proposal '{}' created successfully!\n\n{}\n\nNext steps:\n\
-            1. Edit openspec/changes/{}/proposal.md\n\
-            2. Add specs to openspec/changes/{}/specs/\n\
-            3. Define tasks in openspec/changes/{}/tasks.md\n\
-            4. Run 'openspec:apply-change' to generate code",
-            name, stdout, name, name, name
-        ))
+        Ok(ProposalResult {
+            name: name.to_string(),
+            change_dir: format!("openspec/changes/{}", name),
+            output: String::from_utf8_lossy(&output.stdout).into_owned(),
+        })
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
         Err(anyhow::anyhow!(
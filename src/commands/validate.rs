@@ -1,10 +1,196 @@
 use anyhow::{Result, Context};
+use serde::Serialize;
 use std::path::Path;
 use std::fs;
 
+use super::output::CommandOutput;
+use crate::spec::{parse_spec, Span};
+use crate::spec::paths::{self, SpecLocation};
+use crate::spec::tasks::parse_tasks;
+use crate::utils::config::ExtensionConfig;
+use crate::validation::custom::CustomRules;
+use crate::validation::size::ChangeSizeReport;
+use crate::validation::{count_severity, is_tasks_file, Diagnostic, Severity, Validator};
+
+/// What was found in the validated file
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum FileContents {
+    Spec { requirements: usize, scenarios: usize },
+    Tasks { total: usize, completed: usize },
+}
+
+/// Requirement or task in a validated file, for per-item reports
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckedItem {
+    /// Requirement name, or task label such as `1.2 Write tests`
+    pub name: String,
+    /// Requirement block or task line; findings inside it belong to the item
+    pub span: Span,
+    /// Checkbox state for tasks, `None` for requirements
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed: Option<bool>,
+}
+
+/// Validation outcome for a single file
+#[derive(Debug, Clone, Serialize)]
+pub struct FileReport {
+    pub file: String,
+    pub contents: FileContents,
+    pub items: Vec<CheckedItem>,
+    pub errors: usize,
+    pub warnings: usize,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl FileReport {
+    /// Validate a file's content and apply its suppression comments
+    pub fn check(validator: &Validator, file_path: &str, content: &str) -> Self {
+        Self::check_in_change(validator, file_path, content, None)
+    }
+
+    /// Like [`FileReport::check`], also reporting the enclosing change's total
+    /// size against the change limits when `change_size` is given
+    pub fn check_in_change(
+        validator: &Validator,
+        file_path: &str,
+        content: &str,
+        change_size: Option<&ChangeSizeReport>,
+    ) -> Self {
+        let (mut findings, contents, items) = if is_tasks_file(file_path) {
+            let tasks = parse_tasks(content);
+            let items = tasks
+                .all()
+                .into_iter()
+                .map(|task| CheckedItem {
+                    name: task.label(),
+                    span: task.span,
+                    completed: Some(task.completed),
+                })
+                .collect();
+            (
+                validator.validate_tasks(file_path, &tasks),
+                FileContents::Tasks { total: tasks.total(), completed: tasks.completed_count() },
+                items,
+            )
+        } else {
+            let doc = parse_spec(content);
+            let items = doc
+                .all_requirements()
+                .map(|requirement| CheckedItem {
+                    name: requirement.name.clone(),
+                    span: requirement.span,
+                    completed: None,
+                })
+                .collect();
+            (
+                validator.validate(file_path, &doc),
+                FileContents::Spec {
+                    requirements: doc.requirement_count(),
+                    scenarios: doc.scenario_count(),
+                },
+                items,
+            )
+        };
+
+        if let Some(report) = change_size {
+            let mut change_findings = validator.validate_change_size(file_path, report);
+            change_findings.append(&mut findings);
+            findings = change_findings;
+        }
+        let diagnostics = validator.apply_suppressions(file_path, content, findings);
+        Self {
+            file: file_path.to_string(),
+            contents,
+            items,
+            errors: count_severity(&diagnostics, Severity::Error),
+            warnings: count_severity(&diagnostics, Severity::Warning),
+            diagnostics,
+        }
+    }
+
+    /// Whether the file has no error-level findings
+    pub fn passed(&self) -> bool {
+        self.errors == 0
+    }
+}
+
+/// Result of `openspec:validate-file`
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidateResult {
+    #[serde(flatten)]
+    pub report: FileReport,
+    /// Validation is turned off for this path by `validation.enabled` or an override
+    pub skipped: bool,
+    /// Size of the enclosing change, for files inside a change
+    pub change_size: Option<ChangeSizeReport>,
+}
+
+impl ValidateResult {
+    /// Whether the file has no error-level findings
+    pub fn passed(&self) -> bool {
+        self.report.passed()
+    }
+}
+
+impl CommandOutput for ValidateResult {
+    const COMMAND: &'static str = "validate-file";
+
+    fn to_text(&self) -> String {
+        if self.skipped {
+            return format!(
+                "Validation skipped for: {}\n\nValidation is disabled for this path in the validation settings.",
+                self.report.file
+            );
+        }
+        let report = &self.report;
+
+        let found = match report.contents {
+            FileContents::Spec { requirements, scenarios } => format!(
+                "- {} requirement(s)\n- {} scenario(s)",
+                requirements, scenarios
+            ),
+            FileContents::Tasks { total, completed } => {
+                format!("- {} task(s), {} complete", total, completed)
+            }
+        };
+        let mut summary = format!(
+            "Found:\n{}\n- {} error(s), {} warning(s)",
+            found, report.errors, report.warnings
+        );
+        if let Some(report) = &self.change_size {
+            summary.push_str(&format!("\n\n{}", report.summary()));
+        }
+
+        if report.diagnostics.is_empty() {
+            return format!("✓ Validation passed for: {}\n\n{}", report.file, summary);
+        }
+        let status = if report.errors > 0 { "✗ Validation failed" } else { "⚠ Validation warnings" };
+        format!(
+            "{} for: {}\n\n{}\n\n{}",
+            status,
+            report.file,
+            report.diagnostics.iter()
+                .enumerate()
+                .map(|(i, d)| format!("{}. {}", i + 1, format_diagnostic(d)))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            summary
+        )
+    }
+
+    fn reports(&self) -> Option<Vec<&FileReport>> {
+        Some(if self.skipped { Vec::new() } else { vec![&self.report] })
+    }
+}
+
 /// Handle `openspec:validate-file` command
 /// Validates a spec file
-pub fn handle_validate_file(workspace_path: &Path, file_path: &str) -> Result<String> {
+pub fn handle_validate_file(
+    workspace_path: &Path,
+    file_path: &str,
+    config: &ExtensionConfig,
+) -> Result<ValidateResult> {
     eprintln!("[OpenSpec] Validating file: {}", file_path);
 
     let full_path = workspace_path.join(file_path);
@@ -17,51 +203,54 @@ pub fn handle_validate_file(workspace_path: &Path, file_path: &str) -> Result<St
     let content = fs::read_to_string(&full_path)
         .context("Failed to read file")?;
 
-    // Basic validation (placeholder for Phase 2 LSP validation)
-    let mut warnings = Vec::new();
+    let Some(rules) = config.validation.rules_for(file_path) else {
+        return Ok(ValidateResult {
+            report: FileReport {
+                file: file_path.to_string(),
+                contents: if is_tasks_file(file_path) {
+                    FileContents::Tasks { total: 0, completed: 0 }
+                } else {
+                    FileContents::Spec { requirements: 0, scenarios: 0 }
+                },
+                items: Vec::new(),
+                errors: 0,
+                warnings: 0,
+                diagnostics: Vec::new(),
+            },
+            skipped: true,
+            change_size: None,
+        });
+    };
 
-    // Check if file is in openspec directory
-    if !file_path.starts_with("openspec/") {
-        warnings.push("File is not in openspec/ directory".to_string());
-    }
+    let custom_rules = CustomRules::load(workspace_path)?;
+    let validator = Validator::new(&rules)
+        .with_workspace(workspace_path)
+        .with_custom_rules(&custom_rules);
 
-    // Check for basic spec structure
-    if !content.contains("### Requirement:") && !content.contains("## ADDED") {
-        warnings.push("No requirements or spec deltas found".to_string());
-    }
+    // Specs inside a change are also checked against the change's total size
+    let change_size = match paths::classify(file_path) {
+        SpecLocation::Delta { change_id, .. } | SpecLocation::Change { change_id } => Some(
+            ChangeSizeReport::collect(workspace_path, change_id, &rules)?,
+        ),
+        _ => None,
+    };
+    let report = FileReport::check_in_change(&validator, file_path, &content, change_size.as_ref());
 
-    // Count requirements
-    let requirement_count = content.matches("### Requirement:").count();
-    let scenario_count = content.matches("#### Scenario:").count();
-
-    if requirement_count > 0 && scenario_count == 0 {
-        warnings.push("Requirements found but no scenarios defined".to_string());
-    }
+    Ok(ValidateResult {
+        report,
+        skipped: false,
+        change_size,
+    })
+}
 
-    if warnings.is_empty() {
-        Ok(format!(
-            "✓ Validation passed for: {}\n\n\
-            Found:\n\
-            - {} requirement(s)\n\
-            - {} scenario(s)\n\n\
-            Note: Full LSP-based validation will be available in Phase 2",
-            file_path, requirement_count, scenario_count
-        ))
-    } else {
-        Ok(format!(
-            "⚠ Validation warnings for: {}\n\n{}\n\n\
-            Found:\n\
-            - {} requirement(s)\n\
-            - {} scenario(s)\n\n\
-            Note: Full LSP-based validation will be available in Phase 2",
-            file_path,
-            warnings.iter()
-                .enumerate()
-                .map(|(i, w)| format!("{}. {}", i + 1, w))
-                .collect::<Vec<_>>()
-                .join("\n"),
-            requirement_count,
-            scenario_count
-        ))
-    }
+/// Format a finding as `Line 12:1 warning [OS001 missing-scenario] message`
+pub fn format_diagnostic(diagnostic: &Diagnostic) -> String {
+    format!(
+        "Line {}:{} {} [{}] {}",
+        diagnostic.span.line_number(),
+        diagnostic.span.column_number(),
+        diagnostic.severity.as_str(),
+        diagnostic.code(),
+        diagnostic.message
+    )
 }
@@ -0,0 +1,252 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use super::output::CommandOutput;
+use super::validate::{format_diagnostic, FileReport};
+use crate::spec::paths::{self, SpecLocation};
+use crate::spec::workspace::workspace_files;
+use crate::utils::config::ExtensionConfig;
+use crate::validation::custom::CustomRules;
+use crate::validation::size::ChangeSizeReport;
+use crate::validation::{Severity, Validator};
+
+/// How often a rule fired across the workspace
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RuleTotal {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub errors: usize,
+    pub warnings: usize,
+    /// All findings, including info and hint levels
+    pub total: usize,
+}
+
+/// Result of `openspec:validate-all`
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidateAllResult {
+    /// Whether `openspec/` exists
+    pub initialized: bool,
+    /// Number of files validated
+    pub files_checked: usize,
+    /// Files with validation turned off by the validation settings
+    pub skipped: Vec<String>,
+    pub errors: usize,
+    pub warnings: usize,
+    /// Every validated file, by path
+    pub files: Vec<FileReport>,
+    /// Rules that fired, by rule ID
+    pub rules: Vec<RuleTotal>,
+}
+
+impl ValidateAllResult {
+    /// Whether no file has error-level findings
+    pub fn passed(&self) -> bool {
+        self.errors == 0
+    }
+}
+
+impl CommandOutput for ValidateAllResult {
+    const COMMAND: &'static str = "validate-all";
+
+    fn to_text(&self) -> String {
+        if !self.initialized {
+            return "No openspec/ directory found. Run 'openspec:init' first.".to_string();
+        }
+
+        let status = if self.errors > 0 {
+            "✗ Validation failed"
+        } else if self.warnings > 0 {
+            "⚠ Validation warnings"
+        } else {
+            "✓ Validation passed"
+        };
+        let mut text = format!(
+            "{}: {} error(s), {} warning(s) in {} file(s)",
+            status, self.errors, self.warnings, self.files_checked
+        );
+        if !self.skipped.is_empty() {
+            text.push_str(&format!(", {} skipped", self.skipped.len()));
+        }
+
+        for report in self.files.iter().filter(|f| !f.diagnostics.is_empty()) {
+            text.push_str(&format!(
+                "\n\n{} ({} error(s), {} warning(s))",
+                report.file, report.errors, report.warnings
+            ));
+            for diagnostic in &report.diagnostics {
+                text.push_str(&format!("\n  {}", format_diagnostic(diagnostic)));
+            }
+        }
+
+        if !self.rules.is_empty() {
+            text.push_str("\n\nBy rule:");
+            for rule in &self.rules {
+                text.push_str(&format!(
+                    "\n  {} {}: {} finding(s), {} error(s), {} warning(s)",
+                    rule.rule_id, rule.rule_name, rule.total, rule.errors, rule.warnings
+                ));
+            }
+        }
+        text
+    }
+
+    fn reports(&self) -> Option<Vec<&FileReport>> {
+        Some(self.files.iter().collect())
+    }
+}
+
+/// Handle `openspec:validate-all` command
+/// Validates every source spec and every active change's delta specs and tasks
+pub fn handle_validate_all(workspace_path: &Path, config: &ExtensionConfig) -> Result<ValidateAllResult> {
+    eprintln!("[OpenSpec] Validating workspace");
+
+    let mut result = ValidateAllResult {
+        initialized: workspace_path.join("openspec").is_dir(),
+        files_checked: 0,
+        skipped: Vec::new(),
+        errors: 0,
+        warnings: 0,
+        files: Vec::new(),
+        rules: Vec::new(),
+    };
+    if !result.initialized {
+        return Ok(result);
+    }
+
+    let custom_rules = CustomRules::load(workspace_path)?;
+    let mut totals: BTreeMap<String, RuleTotal> = BTreeMap::new();
+    let mut sized_changes = HashSet::new();
+
+    for file in workspace_files(workspace_path)? {
+        let Some(rules) = config.validation.rules_for(&file) else {
+            result.skipped.push(file);
+            continue;
+        };
+        let content = fs::read_to_string(workspace_path.join(&file))
+            .with_context(|| format!("Failed to read {}", file))?;
+
+        let validator = Validator::new(&rules)
+            .with_workspace(workspace_path)
+            .with_custom_rules(&custom_rules);
+        // Each change's total size is reported once, on its first validated file
+        let change_size = match paths::classify(&file) {
+            SpecLocation::Delta { change_id, .. } | SpecLocation::Change { change_id }
+                if sized_changes.insert(change_id.to_string()) =>
+            {
+                Some(ChangeSizeReport::collect(workspace_path, change_id, &rules)?)
+            }
+            _ => None,
+        };
+        let report = FileReport::check_in_change(&validator, &file, &content, change_size.as_ref());
+        result.files_checked += 1;
+
+        for diagnostic in &report.diagnostics {
+            let total = totals.entry(diagnostic.rule_id.clone()).or_insert_with(|| RuleTotal {
+                rule_id: diagnostic.rule_id.clone(),
+                rule_name: diagnostic.rule_name.clone(),
+                errors: 0,
+                warnings: 0,
+                total: 0,
+            });
+            total.total += 1;
+            match diagnostic.severity {
+                Severity::Error => total.errors += 1,
+                Severity::Warning => total.warnings += 1,
+                _ => {}
+            }
+        }
+
+        result.errors += report.errors;
+        result.warnings += report.warnings;
+        result.files.push(report);
+    }
+
+    result.rules = totals.into_values().collect();
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, file: &str, content: &str) {
+        let path = root.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_validate_all_groups_findings() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let complete = "### Requirement: Login\nThe system SHALL log users in.\n\n\
+                        #### Scenario: Success\n- **WHEN** valid\n- **THEN** a token is issued\n";
+        write(root, "openspec/specs/auth/spec.md", complete);
+        write(root, "openspec/specs/billing/spec.md", "### Requirement: Invoice\nThe system SHALL bill.\n");
+        write(root, "openspec/specs/vendor/spec.md", "# Vendored\n");
+        write(root, "openspec/changes/add-2fa/proposal.md", "# Add 2FA\n");
+        write(root, "openspec/changes/add-2fa/tasks.md", "- [x] 1.1 Add setting\n");
+        write(root, "openspec/changes/archive/old/specs/auth/spec.md", "# Old\n");
+
+        let mut config = ExtensionConfig::default();
+        let result = handle_validate_all(root, &config).unwrap();
+
+        assert_eq!(result.files_checked, 4);
+        let with_findings: Vec<_> = result
+            .files
+            .iter()
+            .filter(|f| !f.diagnostics.is_empty())
+            .map(|f| f.file.as_str())
+            .collect();
+        assert_eq!(
+            with_findings,
+            vec!["openspec/specs/billing/spec.md", "openspec/specs/vendor/spec.md"]
+        );
+        let fired: Vec<_> = result.rules.iter().map(|r| (r.rule_id.as_str(), r.total)).collect();
+        assert_eq!(fired, vec![("OS001", 1), ("OS003", 1)]);
+        assert_eq!(result.errors + result.warnings, 2);
+
+        config.validation.rules.severity.insert("OS003".to_string(), crate::utils::config::RuleSeverity::Error);
+        config.validation.overrides.push(
+            crate::utils::config::ValidationOverride::new(vec!["openspec/specs/billing/**".to_string()])
+                .with_enabled(false),
+        );
+        let result = handle_validate_all(root, &config).unwrap();
+        assert_eq!(result.skipped, vec!["openspec/specs/billing/spec.md"]);
+        assert_eq!(result.errors, 1);
+        assert!(!result.passed());
+        assert!(result.to_text().starts_with("✗ Validation failed: 1 error(s), 0 warning(s) in 3 file(s), 1 skipped"));
+    }
+
+    #[test]
+    fn test_validate_all_checks_change_size() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let spec = "## ADDED Requirements\n### Requirement: Export\nThe system SHALL export CSV.\n\n\
+                    #### Scenario: Export\n- **WHEN** export runs\n- **THEN** a file is written\n";
+        write(root, "openspec/changes/add-export/design.md", &"x".repeat(2500));
+        write(root, "openspec/changes/add-export/specs/export/spec.md", spec);
+        write(root, "openspec/changes/add-export/tasks.md", "- [ ] 1.1 Write exporter\n");
+        write(root, "openspec/changes/add-import/specs/import/spec.md", &spec.replace("Export", "Import"));
+
+        let mut config = ExtensionConfig::default();
+        config.validation.rules.warn_change_size_kb = 1;
+        config.validation.rules.max_change_size_kb = 2;
+        let result = handle_validate_all(root, &config).unwrap();
+
+        let findings: Vec<_> = result
+            .files
+            .iter()
+            .flat_map(|f| f.diagnostics.iter().map(move |d| (f.file.as_str(), d.rule_id.as_str())))
+            .collect();
+        assert_eq!(findings, vec![("openspec/changes/add-export/specs/export/spec.md", "OS018")]);
+        let fired: Vec<_> = result.rules.iter().map(|r| (r.rule_id.as_str(), r.errors)).collect();
+        assert_eq!(fired, vec![("OS018", 1)]);
+        assert_eq!(result.errors, 1);
+        assert!(!result.passed());
+    }
+}
@@ -1,5 +1,13 @@
 use zed_extension_api as zed;
 
+pub mod commands;
+pub mod spec;
+pub mod utils;
+pub mod validation;
+
+/// Name of the language server binary launched by the extension
+const LSP_BINARY: &str = "openspec-lsp";
+
 /// Main extension struct for OpenSpec integration
 ///
 /// Phase 1 Note: Zed extensions currently support language servers and grammar.
@@ -13,20 +21,32 @@ impl zed::Extension for OpenSpecExtension {
     fn new() -> Self {
         eprintln!("[OpenSpec] Extension initialized");
         eprintln!("[OpenSpec] Note: Full command support coming in Phase 2");
-        eprintln!("[OpenSpec] Current: Foundation and LSP preparation");
+        eprintln!("[OpenSpec] Current: Foundation and LSP spec validation");
         Self
     }
 
     /// Provide language server configuration
     ///
-    /// This will be used in Phase 2 to provide real-time spec validation
+    /// Launches the `openspec-lsp` binary (built from `lsp/` in this repo)
+    /// for real-time spec validation. The binary must be on the worktree's PATH.
     fn language_server_command(
         &mut self,
         _language_server_id: &zed::LanguageServerId,
-        _worktree: &zed::Worktree,
+        worktree: &zed::Worktree,
     ) -> zed::Result<zed::Command> {
-        // Phase 2 will implement LSP server for spec validation
-        Err("OpenSpec LSP server not yet implemented - Coming in Phase 2".to_string())
+        let path = worktree.which(LSP_BINARY).ok_or_else(|| {
+            format!(
+                "{} not found on PATH. Install it with:\n\
+                cargo install --path lsp",
+                LSP_BINARY
+            )
+        })?;
+
+        Ok(zed::Command {
+            command: path,
+            args: Vec::new(),
+            env: worktree.shell_env(),
+        })
     }
 }
 
@@ -0,0 +1,158 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::tasks::parse_tasks;
+use crate::utils::fs;
+
+/// Lifecycle status of a change, inferred from its tasks.md checkboxes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeStatus {
+    /// No tasks checked off yet (or no tasks.md)
+    Proposed,
+    InProgress,
+    /// Every task checked off
+    Complete,
+}
+
+impl ChangeStatus {
+    pub fn infer(completed: usize, total: usize) -> Self {
+        if completed == 0 {
+            Self::Proposed
+        } else if completed < total {
+            Self::InProgress
+        } else {
+            Self::Complete
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Proposed => "Proposed",
+            Self::InProgress => "In Progress",
+            Self::Complete => "Complete",
+        }
+    }
+}
+
+/// Overview of an active change directory
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangeSummary {
+    pub id: String,
+    pub status: ChangeStatus,
+    pub completed_tasks: usize,
+    pub total_tasks: usize,
+    /// Number of `specs/<capability>/spec.md` delta files
+    pub spec_deltas: usize,
+    /// Latest modification time of any file in the change, as Unix seconds
+    pub last_modified: Option<u64>,
+}
+
+impl ChangeSummary {
+    pub fn load(change_dir: &Path) -> Result<Self> {
+        let id = change_dir
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let tasks_file = change_dir.join("tasks.md");
+        let (completed_tasks, total_tasks) = if fs::file_exists(&tasks_file) {
+            let tasks = parse_tasks(&fs::read_file(&tasks_file)?);
+            (tasks.completed_count(), tasks.total())
+        } else {
+            (0, 0)
+        };
+
+        let files = fs::list_files_recursive(change_dir)?;
+        let spec_deltas = files
+            .iter()
+            .filter(|f| f.file_name().is_some_and(|n| n == "spec.md"))
+            .filter(|f| f.strip_prefix(change_dir).is_ok_and(|r| r.starts_with("specs")))
+            .count();
+        let last_modified = files
+            .iter()
+            .filter_map(|f| f.metadata().and_then(|m| m.modified()).ok())
+            .max()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs());
+
+        Ok(Self {
+            id,
+            status: ChangeStatus::infer(completed_tasks, total_tasks),
+            completed_tasks,
+            total_tasks,
+            spec_deltas,
+            last_modified,
+        })
+    }
+
+    /// Completed fraction of tasks, 0.0 when there are none
+    pub fn progress(&self) -> f32 {
+        if self.total_tasks == 0 {
+            0.0
+        } else {
+            self.completed_tasks as f32 / self.total_tasks as f32
+        }
+    }
+}
+
+/// Describe a Unix timestamp relative to now, e.g. `3 days ago`
+pub fn format_age(timestamp: u64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(timestamp);
+    let seconds = now.saturating_sub(timestamp);
+
+    let (amount, unit) = match seconds {
+        0..=59 => return "just now".to_string(),
+        60..=3599 => (seconds / 60, "minute"),
+        3600..=86399 => (seconds / 3600, "hour"),
+        _ => (seconds / 86400, "day"),
+    };
+    format!("{} {}{} ago", amount, unit, if amount == 1 { "" } else { "s" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_status_inference() {
+        assert_eq!(ChangeStatus::infer(0, 0), ChangeStatus::Proposed);
+        assert_eq!(ChangeStatus::infer(0, 5), ChangeStatus::Proposed);
+        assert_eq!(ChangeStatus::infer(3, 5), ChangeStatus::InProgress);
+        assert_eq!(ChangeStatus::infer(5, 5), ChangeStatus::Complete);
+    }
+
+    #[test]
+    fn test_load_summary() {
+        let temp_dir = TempDir::new().unwrap();
+        let change_dir = temp_dir.path().join("add-export");
+        fs::create_dir_all(&change_dir.join("specs/export")).unwrap();
+        fs::create_dir_all(&change_dir.join("specs/cli")).unwrap();
+        fs::write_file(&change_dir.join("proposal.md"), "# Add export").unwrap();
+        fs::write_file(&change_dir.join("specs/export/spec.md"), "").unwrap();
+        fs::write_file(&change_dir.join("specs/cli/spec.md"), "").unwrap();
+        fs::write_file(&change_dir.join("tasks.md"), "- [x] 1.1 A\n- [ ] 1.2 B\n- [ ] 1.3 C\n").unwrap();
+
+        let summary = ChangeSummary::load(&change_dir).unwrap();
+
+        assert_eq!(summary.id, "add-export");
+        assert_eq!(summary.status, ChangeStatus::InProgress);
+        assert_eq!((summary.completed_tasks, summary.total_tasks), (1, 3));
+        assert_eq!(summary.spec_deltas, 2);
+        assert!(summary.last_modified.is_some());
+    }
+
+    #[test]
+    fn test_format_age() {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        assert_eq!(format_age(now), "just now");
+        assert_eq!(format_age(now - 3600), "1 hour ago");
+        assert_eq!(format_age(now - 3 * 86400), "3 days ago");
+    }
+}
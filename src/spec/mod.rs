@@ -0,0 +1,9 @@
+pub mod change;
+pub mod model;
+pub mod parser;
+pub mod paths;
+pub mod tasks;
+pub mod workspace;
+
+pub use model::*;
+pub use parser::{parse_spec, LineIndex};
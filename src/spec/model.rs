@@ -0,0 +1,256 @@
+use serde::Serialize;
+
+/// Zero-based line/column position in a spec file.
+///
+/// Columns count Unicode scalar values, not bytes, so they can be shown to
+/// users directly. The LSP layer converts them to UTF-16 offsets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Source range covered by a node, as byte offsets plus line/column positions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub start_pos: Position,
+    pub end_pos: Position,
+}
+
+impl Span {
+    /// Check whether a byte offset falls inside this span (end inclusive)
+    pub fn contains(&self, offset: usize) -> bool {
+        offset >= self.start && offset <= self.end
+    }
+
+    /// One-based line number, for human-readable output
+    pub fn line_number(&self) -> usize {
+        self.start_pos.line + 1
+    }
+
+    /// One-based column number, for human-readable output
+    pub fn column_number(&self) -> usize {
+        self.start_pos.column + 1
+    }
+}
+
+/// Markdown ATX heading (`#` through `######`)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Heading {
+    pub level: usize,
+    pub text: String,
+    /// The whole heading line, without the trailing newline
+    pub span: Span,
+    /// Just the heading text after the `#` markers
+    pub text_span: Span,
+}
+
+impl Heading {
+    /// Level OpenSpec expects for delta section (`##`), requirement (`###`)
+    /// and scenario (`####`) headings; `None` for any other heading
+    pub fn expected_level(&self) -> Option<usize> {
+        let has_label = |label: &str| {
+            self.text.get(..label.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(label))
+        };
+        if super::parser::delta_keyword(&self.text).is_some() {
+            Some(2)
+        } else if has_label("Requirement:") {
+            Some(3)
+        } else if has_label("Scenario:") {
+            Some(4)
+        } else {
+            None
+        }
+    }
+}
+
+/// Kind of a `## ADDED|MODIFIED|REMOVED|RENAMED Requirements` section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DeltaKind {
+    Added,
+    Modified,
+    Removed,
+    Renamed,
+}
+
+impl DeltaKind {
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword.to_ascii_uppercase().as_str() {
+            "ADDED" => Some(Self::Added),
+            "MODIFIED" => Some(Self::Modified),
+            "REMOVED" => Some(Self::Removed),
+            "RENAMED" => Some(Self::Renamed),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Added => "ADDED",
+            Self::Modified => "MODIFIED",
+            Self::Removed => "REMOVED",
+            Self::Renamed => "RENAMED",
+        }
+    }
+}
+
+/// Keyword introducing a scenario step (`- **WHEN** ...`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum StepKeyword {
+    Given,
+    When,
+    Then,
+    And,
+}
+
+impl StepKeyword {
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword.to_ascii_uppercase().as_str() {
+            "GIVEN" => Some(Self::Given),
+            "WHEN" => Some(Self::When),
+            "THEN" => Some(Self::Then),
+            "AND" => Some(Self::And),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Given => "GIVEN",
+            Self::When => "WHEN",
+            Self::Then => "THEN",
+            Self::And => "AND",
+        }
+    }
+}
+
+/// Contiguous run of source text, e.g. a requirement's normative statement
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TextBlock {
+    pub text: String,
+    pub span: Span,
+}
+
+impl TextBlock {
+    /// Span of `start..end`, given as byte offsets relative to the block text
+    pub fn sub_span(&self, start: usize, end: usize) -> Span {
+        let position = |offset: usize| {
+            let before = &self.text[..offset];
+            match before.rfind('\n') {
+                Some(newline) => Position {
+                    line: self.span.start_pos.line + before.matches('\n').count(),
+                    column: before[newline + 1..].chars().count(),
+                },
+                None => Position {
+                    line: self.span.start_pos.line,
+                    column: self.span.start_pos.column + before.chars().count(),
+                },
+            }
+        };
+        Span {
+            start: self.span.start + start,
+            end: self.span.start + end,
+            start_pos: position(start),
+            end_pos: position(end),
+        }
+    }
+}
+
+/// Single WHEN/THEN/AND bullet inside a scenario
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Step {
+    pub keyword: StepKeyword,
+    pub text: String,
+    pub span: Span,
+}
+
+/// `#### Scenario: <name>` block
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Scenario {
+    pub name: String,
+    pub heading: Heading,
+    pub name_span: Span,
+    pub steps: Vec<Step>,
+    pub span: Span,
+}
+
+/// `### Requirement: <name>` block with its body and scenarios
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Requirement {
+    pub name: String,
+    pub heading: Heading,
+    pub name_span: Span,
+    /// Text between the heading and the first scenario
+    pub body: Option<TextBlock>,
+    pub scenarios: Vec<Scenario>,
+    pub span: Span,
+    /// Delta section the requirement appears in, if any
+    pub delta: Option<DeltaKind>,
+}
+
+/// Side of a RENAMED entry (`- FROM: ...` or `- TO: ...`)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RenameTarget {
+    pub name: String,
+    pub span: Span,
+}
+
+/// FROM/TO pair inside a `## RENAMED Requirements` section
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Rename {
+    pub from: Option<RenameTarget>,
+    pub to: Option<RenameTarget>,
+    pub span: Span,
+}
+
+/// `## <KIND> Requirements` section of a change spec
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeltaSection {
+    pub kind: DeltaKind,
+    pub heading: Heading,
+    pub requirements: Vec<Requirement>,
+    pub renames: Vec<Rename>,
+    pub span: Span,
+}
+
+/// Parsed OpenSpec markdown file
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SpecDocument {
+    /// First level-1 heading
+    pub title: Option<Heading>,
+    /// Every heading outside code fences, in document order
+    pub headings: Vec<Heading>,
+    /// Requirements outside any delta section
+    pub requirements: Vec<Requirement>,
+    pub deltas: Vec<DeltaSection>,
+    /// Scenarios that appear before any requirement heading
+    pub orphan_scenarios: Vec<Scenario>,
+    pub span: Span,
+}
+
+impl SpecDocument {
+    /// Check whether this document uses delta sections (i.e. is a change spec)
+    pub fn is_delta(&self) -> bool {
+        !self.deltas.is_empty()
+    }
+
+    /// Iterate over all requirements, including those in delta sections
+    pub fn all_requirements(&self) -> impl Iterator<Item = &Requirement> {
+        self.requirements
+            .iter()
+            .chain(self.deltas.iter().flat_map(|d| d.requirements.iter()))
+    }
+
+    pub fn requirement_count(&self) -> usize {
+        self.all_requirements().count()
+    }
+
+    pub fn scenario_count(&self) -> usize {
+        self.all_requirements().map(|r| r.scenarios.len()).sum::<usize>()
+            + self.orphan_scenarios.len()
+    }
+}
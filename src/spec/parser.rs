@@ -0,0 +1,524 @@
+use super::model::*;
+
+/// Maps byte offsets in a source string to line/column positions
+pub struct LineIndex<'a> {
+    source: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(source: &'a str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(source.match_indices('\n').map(|(i, _)| i + 1));
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    /// Number of lines, counting a trailing empty line after a final newline
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Byte offset where a zero-based line starts
+    pub fn line_start(&self, line: usize) -> Option<usize> {
+        self.line_starts.get(line).copied()
+    }
+
+    /// Convert a byte offset into a zero-based line/column position
+    pub fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.source.len());
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line];
+        let column = self.source[line_start..offset].chars().count();
+        Position { line, column }
+    }
+
+    /// Build a span covering `start..end`
+    pub fn span(&self, start: usize, end: usize) -> Span {
+        Span {
+            start,
+            end,
+            start_pos: self.position(start),
+            end_pos: self.position(end),
+        }
+    }
+}
+
+/// Parse OpenSpec markdown into a typed document tree
+///
+/// Parsing never fails: malformed structure (scenarios outside requirements,
+/// headings at the wrong level, half-written RENAMED entries) is preserved in
+/// the tree so validation can report it with an exact location.
+pub fn parse_spec(source: &str) -> SpecDocument {
+    Parser::new(source).parse()
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    index: LineIndex<'a>,
+    doc: SpecDocument,
+    delta: Option<DeltaSection>,
+    requirement: Option<Requirement>,
+    scenario: Option<Scenario>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            index: LineIndex::new(source),
+            doc: SpecDocument::default(),
+            delta: None,
+            requirement: None,
+            scenario: None,
+        }
+    }
+
+    fn parse(mut self) -> SpecDocument {
+        let mut fence: Option<char> = None;
+        let mut offset = 0;
+
+        for raw in self.source.split_inclusive('\n') {
+            let start = offset;
+            offset += raw.len();
+
+            let line = raw.trim_end_matches(['\n', '\r']);
+            let end = start + line.len();
+            let trimmed = line.trim_start();
+
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            // Headings and bullets inside fenced code blocks are just content
+            if let Some(marker) = fence {
+                if trimmed.starts_with(&marker.to_string().repeat(3)) {
+                    fence = None;
+                }
+                self.extend_open_nodes(end);
+                continue;
+            }
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                fence = trimmed.chars().next();
+                self.extend_open_nodes(end);
+                continue;
+            }
+
+            if let Some(heading) = self.parse_heading(line, start) {
+                self.handle_heading(heading);
+            } else {
+                self.handle_content(line, start);
+            }
+            self.extend_open_nodes(end);
+        }
+
+        self.close_delta();
+        self.doc.span = self.index.span(0, self.source.len());
+        self.doc
+    }
+
+    fn handle_heading(&mut self, heading: Heading) {
+        self.doc.headings.push(heading.clone());
+
+        if let Some(kind) = delta_keyword(&heading.text) {
+            self.close_delta();
+            self.delta = Some(DeltaSection {
+                kind,
+                span: heading.span,
+                heading,
+                requirements: Vec::new(),
+                renames: Vec::new(),
+            });
+        } else if let Some((name, name_span)) = self.labelled(&heading, "Requirement:") {
+            self.close_requirement();
+            self.requirement = Some(Requirement {
+                name,
+                name_span,
+                body: None,
+                scenarios: Vec::new(),
+                span: heading.span,
+                delta: self.delta.as_ref().map(|d| d.kind),
+                heading,
+            });
+        } else if let Some((name, name_span)) = self.labelled(&heading, "Scenario:") {
+            self.close_scenario();
+            self.scenario = Some(Scenario {
+                name,
+                name_span,
+                steps: Vec::new(),
+                span: heading.span,
+                heading,
+            });
+        } else {
+            // Any other heading ends every open node at the same or deeper level
+            if self.scenario.as_ref().is_some_and(|s| heading.level <= s.heading.level) {
+                self.close_scenario();
+            }
+            if self.requirement.as_ref().is_some_and(|r| heading.level <= r.heading.level) {
+                self.close_requirement();
+            }
+            if self.delta.as_ref().is_some_and(|d| heading.level <= d.heading.level) {
+                self.close_delta();
+            }
+            if heading.level == 1 && self.doc.title.is_none() {
+                self.doc.title = Some(heading);
+            }
+        }
+    }
+
+    fn handle_content(&mut self, line: &str, start: usize) {
+        if let Some(scenario) = self.scenario.as_mut() {
+            if let Some(step) = parse_step(&self.index, line, start) {
+                scenario.steps.push(step);
+            }
+        } else if let Some(requirement) = self.requirement.as_mut() {
+            if requirement.scenarios.is_empty() {
+                let end = start + line.trim_end().len();
+                let body_start = start + (line.len() - line.trim_start().len());
+                let span = match &requirement.body {
+                    Some(body) => self.index.span(body.span.start, end),
+                    None => self.index.span(body_start, end),
+                };
+                requirement.body = Some(TextBlock {
+                    text: self.source[span.start..span.end].to_string(),
+                    span,
+                });
+            }
+        } else if let Some(delta) = self.delta.as_mut() {
+            if delta.kind == DeltaKind::Renamed {
+                parse_rename_line(&self.index, delta, line, start);
+            }
+        }
+    }
+
+    /// Extend every open node so its span reaches `end`
+    fn extend_open_nodes(&mut self, end: usize) {
+        let end_pos = self.index.position(end);
+        let spans = [
+            self.scenario.as_mut().map(|s| &mut s.span),
+            self.requirement.as_mut().map(|r| &mut r.span),
+            self.delta.as_mut().map(|d| &mut d.span),
+        ];
+        for span in spans.into_iter().flatten() {
+            span.end = end;
+            span.end_pos = end_pos;
+        }
+    }
+
+    fn close_scenario(&mut self) {
+        if let Some(scenario) = self.scenario.take() {
+            match self.requirement.as_mut() {
+                Some(requirement) => requirement.scenarios.push(scenario),
+                None => self.doc.orphan_scenarios.push(scenario),
+            }
+        }
+    }
+
+    fn close_requirement(&mut self) {
+        self.close_scenario();
+        if let Some(requirement) = self.requirement.take() {
+            match self.delta.as_mut() {
+                Some(delta) => delta.requirements.push(requirement),
+                None => self.doc.requirements.push(requirement),
+            }
+        }
+    }
+
+    fn close_delta(&mut self) {
+        self.close_requirement();
+        if let Some(delta) = self.delta.take() {
+            self.doc.deltas.push(delta);
+        }
+    }
+
+    /// Recognise an ATX heading (`## Text`) on a single line
+    fn parse_heading(&self, line: &str, start: usize) -> Option<Heading> {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        if indent > 3 {
+            return None;
+        }
+        let rest = &line[indent..];
+        let level = rest.len() - rest.trim_start_matches('#').len();
+        if level == 0 || level > 6 {
+            return None;
+        }
+        let after = &rest[level..];
+        if !after.is_empty() && !after.starts_with([' ', '\t']) {
+            return None;
+        }
+
+        // Drop an optional closing sequence of `#`s
+        let mut text = after.trim();
+        let stripped = text.trim_end_matches('#');
+        if stripped.len() != text.len() && (stripped.is_empty() || stripped.ends_with([' ', '\t'])) {
+            text = stripped.trim_end();
+        }
+
+        let text_start = if text.is_empty() {
+            start + indent + level
+        } else {
+            start + indent + level + (after.len() - after.trim_start().len())
+        };
+
+        Some(Heading {
+            level,
+            text: text.to_string(),
+            span: self.index.span(start, start + line.trim_end().len()),
+            text_span: self.index.span(text_start, text_start + text.len()),
+        })
+    }
+
+    /// Split `Label: name` heading text into the name and its span
+    fn labelled(&self, heading: &Heading, label: &str) -> Option<(String, Span)> {
+        let text = &heading.text;
+        if !text.get(..label.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(label)) {
+            return None;
+        }
+        let rest = &text[label.len()..];
+        let name = rest.trim();
+        let name_start = heading.text_span.start + label.len() + (rest.len() - rest.trim_start().len());
+        Some((
+            name.to_string(),
+            self.index.span(name_start, name_start + name.len()),
+        ))
+    }
+}
+
+/// Match `ADDED Requirements` style heading text
+pub(crate) fn delta_keyword(text: &str) -> Option<DeltaKind> {
+    let mut words = text.split_whitespace();
+    let keyword = words.next()?;
+    let kind = DeltaKind::from_keyword(keyword)?;
+    let noun = words.next()?;
+    if !noun.to_ascii_lowercase().starts_with("requirement") || words.next().is_some() {
+        return None;
+    }
+    Some(kind)
+}
+
+/// Strip a `- ` or `* ` list marker, returning the remaining text and its offset in `line`
+fn strip_bullet(line: &str) -> Option<(&str, usize)> {
+    let trimmed = line.trim_start();
+    let indent = line.len() - trimmed.len();
+    let rest = trimmed
+        .strip_prefix("- ")
+        .or_else(|| trimmed.strip_prefix("* "))?;
+    let inner = rest.trim_start();
+    Some((inner, indent + (trimmed.len() - inner.len())))
+}
+
+/// Split a `**KEYWORD**` or `KEYWORD:` prefix off a bullet body
+fn split_keyword(text: &str) -> Option<(&str, &str)> {
+    let (keyword, rest) = if let Some(bold) = text.strip_prefix("**") {
+        let close = bold.find("**")?;
+        (&bold[..close], &bold[close + 2..])
+    } else {
+        let end = text
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(text.len());
+        (&text[..end], &text[end..])
+    };
+    let keyword = keyword.trim().trim_end_matches(':');
+    let rest = rest.trim_start();
+    let rest = rest.strip_prefix(':').unwrap_or(rest).trim();
+    Some((keyword, rest))
+}
+
+/// Parse a `- **WHEN** something happens` scenario step
+fn parse_step(index: &LineIndex, line: &str, start: usize) -> Option<Step> {
+    let (body, body_offset) = strip_bullet(line)?;
+    let (keyword, text) = split_keyword(body)?;
+    let keyword = StepKeyword::from_keyword(keyword)?;
+    let span_start = start + line.len() - line.trim_start().len();
+    let span_end = start + body_offset + body.trim_end().len();
+    Some(Step {
+        keyword,
+        text: text.to_string(),
+        span: index.span(span_start, span_end),
+    })
+}
+
+/// Parse a `- FROM: ...` or `- TO: ...` line of a RENAMED section
+fn parse_rename_line(index: &LineIndex, delta: &mut DeltaSection, line: &str, start: usize) {
+    let Some((body, body_offset)) = strip_bullet(line) else {
+        return;
+    };
+    let Some((keyword, value)) = split_keyword(body) else {
+        return;
+    };
+
+    let name = value
+        .trim_matches('`')
+        .trim()
+        .trim_start_matches('#')
+        .trim();
+    let name = match name.get(..12) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("Requirement:") => name[12..].trim(),
+        _ => name,
+    };
+
+    let line_span = index.span(
+        start + line.len() - line.trim_start().len(),
+        start + body_offset + body.trim_end().len(),
+    );
+    let name_start = match line[body_offset..].rfind(name) {
+        Some(pos) if !name.is_empty() => start + body_offset + pos,
+        _ => line_span.end,
+    };
+    let target = RenameTarget {
+        name: name.to_string(),
+        span: index.span(name_start, name_start + name.len()),
+    };
+
+    if keyword.eq_ignore_ascii_case("FROM") {
+        delta.renames.push(Rename {
+            from: Some(target),
+            to: None,
+            span: line_span,
+        });
+    } else if keyword.eq_ignore_ascii_case("TO") {
+        match delta.renames.last_mut() {
+            Some(rename) if rename.to.is_none() => {
+                rename.to = Some(target);
+                rename.span = index.span(rename.span.start, line_span.end);
+            }
+            _ => delta.renames.push(Rename {
+                from: None,
+                to: Some(target),
+                span: line_span,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = "# Auth Specification
+
+## Purpose
+Authentication for the API.
+
+## Requirements
+### Requirement: User Login
+The system SHALL authenticate users.
+
+#### Scenario: Valid credentials
+- **WHEN** a user submits valid credentials
+- **THEN** a session token is returned
+- **AND** the login is recorded
+
+### Requirement: Logout
+The system SHALL end sessions.
+";
+
+    #[test]
+    fn test_parse_requirements_and_scenarios() {
+        let doc = parse_spec(SPEC);
+
+        assert_eq!(doc.title.as_ref().unwrap().text, "Auth Specification");
+        assert!(!doc.is_delta());
+        assert_eq!(doc.requirement_count(), 2);
+        assert_eq!(doc.scenario_count(), 1);
+
+        let login = &doc.requirements[0];
+        assert_eq!(login.name, "User Login");
+        assert_eq!(login.heading.level, 3);
+        assert_eq!(login.heading.span.line_number(), 7);
+        assert_eq!(login.body.as_ref().unwrap().text, "The system SHALL authenticate users.");
+
+        let scenario = &login.scenarios[0];
+        assert_eq!(scenario.name, "Valid credentials");
+        let keywords: Vec<_> = scenario.steps.iter().map(|s| s.keyword).collect();
+        assert_eq!(keywords, vec![StepKeyword::When, StepKeyword::Then, StepKeyword::And]);
+        assert_eq!(scenario.steps[1].text, "a session token is returned");
+
+        assert!(doc.requirements[1].scenarios.is_empty());
+    }
+
+    #[test]
+    fn test_spans_point_at_source() {
+        let doc = parse_spec(SPEC);
+        let login = &doc.requirements[0];
+
+        assert_eq!(&SPEC[login.name_span.start..login.name_span.end], "User Login");
+        assert_eq!(login.name_span.start_pos, Position { line: 6, column: 17 });
+        assert!(SPEC[login.span.start..login.span.end].ends_with("the login is recorded"));
+
+        let step = &login.scenarios[0].steps[0];
+        assert!(SPEC[step.span.start..step.span.end].starts_with("- **WHEN**"));
+    }
+
+    #[test]
+    fn test_parse_delta_sections() {
+        let source = "## ADDED Requirements
+### Requirement: Two Factor
+The system MUST support TOTP.
+
+#### Scenario: Code accepted
+- WHEN a valid code is entered
+- THEN access is granted
+
+## REMOVED Requirements
+### Requirement: Legacy Login
+
+## RENAMED Requirements
+- FROM: `### Requirement: Login`
+- TO: `### Requirement: User Login`
+";
+        let doc = parse_spec(source);
+
+        assert!(doc.is_delta());
+        assert_eq!(doc.deltas.len(), 3);
+        assert_eq!(doc.deltas[0].kind, DeltaKind::Added);
+        assert_eq!(doc.deltas[0].requirements[0].delta, Some(DeltaKind::Added));
+        assert_eq!(doc.deltas[0].requirements[0].scenarios[0].steps.len(), 2);
+        assert_eq!(doc.deltas[1].requirements[0].name, "Legacy Login");
+
+        let rename = &doc.deltas[2].renames[0];
+        let from = rename.from.as_ref().unwrap();
+        assert_eq!(from.name, "Login");
+        assert_eq!(&source[from.span.start..from.span.end], "Login");
+        assert_eq!(rename.to.as_ref().unwrap().name, "User Login");
+    }
+
+    #[test]
+    fn test_code_fences_and_orphans() {
+        let source = "#### Scenario: Floating
+- **WHEN** nothing
+
+```markdown
+### Requirement: Not Real
+```
+";
+        let doc = parse_spec(source);
+
+        assert_eq!(doc.requirement_count(), 0);
+        assert_eq!(doc.orphan_scenarios.len(), 1);
+        assert_eq!(doc.orphan_scenarios[0].steps.len(), 1);
+    }
+
+    #[test]
+    fn test_line_index_counts_characters() {
+        let source = "é\n## Requirement: ünïcode";
+        let index = LineIndex::new(source);
+        let offset = source.find("ünïcode").unwrap();
+
+        assert_eq!(index.position(offset), Position { line: 1, column: 16 });
+        assert_eq!(index.line_count(), 2);
+    }
+
+    #[test]
+    fn test_non_ascii_headings() {
+        let source = "## Описание\n### Requiremen€x\n#### Scenari€ abc\n### Requirement: Вход\n#### Scenario: Успех\n";
+        let doc = parse_spec(source);
+
+        assert_eq!(doc.headings.len(), 5);
+        assert_eq!(doc.requirements.len(), 1);
+        assert_eq!(doc.requirements[0].name, "Вход");
+        assert_eq!(doc.requirements[0].scenarios[0].name, "Успех");
+    }
+}
@@ -0,0 +1,72 @@
+//! Conventions for where specs live inside an OpenSpec workspace
+
+/// What a workspace-relative path under `openspec/` refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpecLocation<'a> {
+    /// `openspec/specs/<capability>/spec.md`
+    Source { capability: &'a str },
+    /// `openspec/changes/<change_id>/specs/<capability>/spec.md`
+    Delta { change_id: &'a str, capability: &'a str },
+    /// Any other file in an active change, e.g. `proposal.md` or `tasks.md`
+    Change { change_id: &'a str },
+    /// Anything under `openspec/changes/archive/`
+    Archived,
+    Other,
+}
+
+/// Classify a workspace-relative path using `/` separators
+pub fn classify(path: &str) -> SpecLocation<'_> {
+    let parts: Vec<&str> = path.split('/').filter(|p| !p.is_empty()).collect();
+    match parts.as_slice() {
+        ["openspec", "specs", capability, "spec.md"] => SpecLocation::Source { capability },
+        ["openspec", "changes", "archive", ..] => SpecLocation::Archived,
+        ["openspec", "changes", change_id, "specs", capability, "spec.md"] => SpecLocation::Delta {
+            change_id,
+            capability,
+        },
+        ["openspec", "changes", change_id, _, ..] => SpecLocation::Change { change_id },
+        _ => SpecLocation::Other,
+    }
+}
+
+/// Workspace-relative path of the source spec for a capability
+pub fn source_spec_path(capability: &str) -> String {
+    format!("openspec/specs/{}/spec.md", capability)
+}
+
+/// Workspace-relative path of a change's delta spec for a capability
+pub fn delta_spec_path(change_id: &str, capability: &str) -> String {
+    format!("openspec/changes/{}/specs/{}/spec.md", change_id, capability)
+}
+
+/// Normalise a requirement name for matching across files
+pub fn normalize_name(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_paths() {
+        assert_eq!(
+            classify("openspec/specs/auth/spec.md"),
+            SpecLocation::Source { capability: "auth" }
+        );
+        assert_eq!(
+            classify("openspec/changes/add-2fa/specs/auth/spec.md"),
+            SpecLocation::Delta { change_id: "add-2fa", capability: "auth" }
+        );
+        assert_eq!(
+            classify("openspec/changes/add-2fa/tasks.md"),
+            SpecLocation::Change { change_id: "add-2fa" }
+        );
+        assert_eq!(
+            classify("openspec/changes/archive/2025-01-01-add-2fa/specs/auth/spec.md"),
+            SpecLocation::Archived
+        );
+        assert_eq!(classify("docs/spec.md"), SpecLocation::Other);
+        assert_eq!(normalize_name("  User   Login "), "User Login");
+    }
+}
@@ -0,0 +1,349 @@
+use serde::Serialize;
+
+use super::model::Span;
+use super::parser::LineIndex;
+
+/// Checkbox item from a change's `tasks.md`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Task {
+    /// Leading `X.Y` identifier, if the task is numbered
+    pub id: Option<String>,
+    /// Task text after the ID
+    pub description: String,
+    pub completed: bool,
+    /// Nesting depth, 0 for top-level tasks
+    pub depth: usize,
+    /// The checkbox line, without leading indentation
+    pub span: Span,
+    /// Span of the leading numeric token, even when it is malformed
+    pub id_span: Option<Span>,
+    pub children: Vec<Task>,
+}
+
+impl Task {
+    /// `1.2 Write tests`, or just the description for unnumbered tasks
+    pub fn label(&self) -> String {
+        match &self.id {
+            Some(id) => format!("{} {}", id, self.description),
+            None => self.description.clone(),
+        }
+    }
+}
+
+/// `## 1. Implementation` heading and the tasks below it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaskSection {
+    /// Leading section number (`1`), if the heading is numbered
+    pub number: Option<String>,
+    pub title: String,
+    /// Heading line; tasks before the first heading get an empty section
+    /// whose span covers the start of the file
+    pub span: Span,
+    pub tasks: Vec<Task>,
+}
+
+/// Parsed `tasks.md`
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TaskList {
+    pub sections: Vec<TaskSection>,
+}
+
+impl TaskList {
+    /// Every task in document order, including nested subtasks
+    pub fn all(&self) -> Vec<&Task> {
+        fn walk<'a>(tasks: &'a [Task], out: &mut Vec<&'a Task>) {
+            for task in tasks {
+                out.push(task);
+                walk(&task.children, out);
+            }
+        }
+
+        let mut out = Vec::new();
+        for section in &self.sections {
+            walk(&section.tasks, &mut out);
+        }
+        out
+    }
+
+    pub fn total(&self) -> usize {
+        self.all().len()
+    }
+
+    pub fn completed_count(&self) -> usize {
+        self.all().iter().filter(|t| t.completed).count()
+    }
+
+    pub fn incomplete(&self) -> Vec<&Task> {
+        self.all().into_iter().filter(|t| !t.completed).collect()
+    }
+
+    /// Find a task by its `X.Y` ID
+    pub fn find(&self, id: &str) -> Option<&Task> {
+        self.all().into_iter().find(|t| t.id.as_deref() == Some(id))
+    }
+
+    /// Find the task whose checkbox line contains a byte offset
+    pub fn task_at(&self, offset: usize) -> Option<&Task> {
+        self.all().into_iter().find(|t| t.span.contains(offset))
+    }
+}
+
+/// Check whether an ID has the `X.Y` (or deeper `X.Y.Z`) numeric form
+pub fn is_well_formed_id(id: &str) -> bool {
+    let parts: Vec<&str> = id.split('.').collect();
+    parts.len() >= 2 && parts.iter().all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Parse numbered sections and `- [ ]` / `- [x]` checkboxes from a tasks file
+///
+/// Nesting follows indentation: a checkbox indented deeper than the one
+/// before it becomes that task's subtask.
+pub fn parse_tasks(source: &str) -> TaskList {
+    let index = LineIndex::new(source);
+    let mut sections: Vec<(TaskSection, Vec<(usize, Task)>)> = Vec::new();
+    // Marker and length of the open code fence, e.g. ('`', 4) for ````
+    let mut fence: Option<(char, usize)> = None;
+    let mut offset = 0;
+
+    for raw in source.split_inclusive('\n') {
+        let start = offset;
+        offset += raw.len();
+
+        let line = raw.trim_end_matches(['\n', '\r']);
+        let trimmed = line.trim_start();
+        let indent_bytes = line.len() - trimmed.len();
+
+        if let Some((marker, len)) = fence {
+            // Only a run of the same marker, at least as long, closes the fence
+            if fence_marker(trimmed).is_some_and(|(m, l)| m == marker && l >= len)
+                && trimmed.trim_start_matches(marker).trim().is_empty()
+            {
+                fence = None;
+            }
+            continue;
+        }
+        if let Some(marker) = fence_marker(trimmed) {
+            fence = Some(marker);
+            continue;
+        }
+
+        if let Some(title) = heading_text(trimmed) {
+            let (number, title) = split_section_number(title);
+            sections.push((
+                TaskSection {
+                    number,
+                    title: title.to_string(),
+                    span: index.span(start + indent_bytes, start + line.trim_end().len()),
+                    tasks: Vec::new(),
+                },
+                Vec::new(),
+            ));
+            continue;
+        }
+
+        let Some(task) = parse_task_line(&index, trimmed, start + indent_bytes) else {
+            continue;
+        };
+
+        if sections.is_empty() {
+            sections.push((
+                TaskSection {
+                    number: None,
+                    title: String::new(),
+                    span: index.span(0, 0),
+                    tasks: Vec::new(),
+                },
+                Vec::new(),
+            ));
+        }
+        let indent = line[..indent_bytes]
+            .chars()
+            .map(|c| if c == '\t' { 4 } else { 1 })
+            .sum();
+        if let Some((_, items)) = sections.last_mut() {
+            items.push((indent, task));
+        }
+    }
+
+    TaskList {
+        sections: sections
+            .into_iter()
+            .map(|(mut section, items)| {
+                section.tasks = build_tree(&mut items.into_iter().peekable(), None, 0);
+                section
+            })
+            .collect(),
+    }
+}
+
+/// Opening marker of a code fence: its character and run length
+fn fence_marker(trimmed: &str) -> Option<(char, usize)> {
+    let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = trimmed.len() - trimmed.trim_start_matches(marker).len();
+    (len >= 3).then_some((marker, len))
+}
+
+/// Text of an ATX heading (`## 1. Setup`); `#tag` and the like are not headings
+fn heading_text(trimmed: &str) -> Option<&str> {
+    let level = trimmed.len() - trimmed.trim_start_matches('#').len();
+    let after = &trimmed[level..];
+    if level == 0 || level > 6 || !(after.is_empty() || after.starts_with([' ', '\t'])) {
+        return None;
+    }
+    Some(after.trim())
+}
+
+/// Split `1. Implementation` into `("1", "Implementation")`
+fn split_section_number(title: &str) -> (Option<String>, &str) {
+    let token = title.split_whitespace().next().unwrap_or("");
+    let number = token.trim_end_matches('.');
+    if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        (Some(number.to_string()), title[token.len()..].trim())
+    } else {
+        (None, title)
+    }
+}
+
+/// Parse a single `- [x] 1.2 Description` line (already stripped of indentation)
+fn parse_task_line(index: &LineIndex, line: &str, start: usize) -> Option<Task> {
+    let rest = line
+        .strip_prefix("- [")
+        .or_else(|| line.strip_prefix("* ["))?;
+    let (mark, text) = rest.split_at_checked(1)?;
+    let text = text.strip_prefix(']')?;
+    let completed = match mark {
+        " " => false,
+        "x" | "X" => true,
+        _ => return None,
+    };
+
+    let body = text.trim();
+    let body_start = start + (line.len() - text.len()) + (text.len() - text.trim_start().len());
+
+    // A leading token starting with a digit is the task ID
+    let token = body.split_whitespace().next().unwrap_or("");
+    let (id, id_span, description) = if token.starts_with(|c: char| c.is_ascii_digit()) {
+        let id = token.trim_end_matches('.');
+        (
+            Some(id.to_string()),
+            Some(index.span(body_start, body_start + id.len())),
+            body[token.len()..].trim(),
+        )
+    } else {
+        (None, None, body)
+    };
+
+    Some(Task {
+        id,
+        description: description.to_string(),
+        completed,
+        depth: 0,
+        span: index.span(start, start + line.trim_end().len()),
+        id_span,
+        children: Vec::new(),
+    })
+}
+
+/// Nest tasks by indentation, consuming items deeper than `parent_indent`
+fn build_tree<I>(
+    items: &mut std::iter::Peekable<I>,
+    parent_indent: Option<usize>,
+    depth: usize,
+) -> Vec<Task>
+where
+    I: Iterator<Item = (usize, Task)>,
+{
+    let mut tasks = Vec::new();
+    while let Some((indent, _)) = items.peek() {
+        if parent_indent.is_some_and(|parent| *indent <= parent) {
+            break;
+        }
+        let Some((indent, mut task)) = items.next() else {
+            break;
+        };
+        task.depth = depth;
+        task.children = build_tree(items, Some(indent), depth + 1);
+        tasks.push(task);
+    }
+    tasks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TASKS: &str = "# Tasks
+
+## 1. Implementation
+- [x] 1.1 Create parser
+- [ ] 1.2 Wire into commands
+  - [x] 1.2.1 Archive
+  - [ ] 1.2.2 List
+
+## 2. Testing
+- [X] 2.1 Unit tests
+
+```markdown
+- [ ] 9.9 Example only
+```
+";
+
+    #[test]
+    fn test_parse_sections_and_nesting() {
+        let list = parse_tasks(TASKS);
+
+        assert_eq!(list.sections.len(), 3);
+        let implementation = &list.sections[1];
+        assert_eq!(implementation.number.as_deref(), Some("1"));
+        assert_eq!(implementation.title, "Implementation");
+        assert_eq!(implementation.tasks.len(), 2);
+
+        let wire = &implementation.tasks[1];
+        assert_eq!(wire.id.as_deref(), Some("1.2"));
+        assert_eq!(wire.description, "Wire into commands");
+        assert_eq!(wire.children.len(), 2);
+        assert_eq!(wire.children[1].depth, 1);
+        assert_eq!(wire.children[1].span.line_number(), 7);
+        assert_eq!(wire.children[1].span.start_pos.column, 2);
+    }
+
+    #[test]
+    fn test_completion_tracking() {
+        let list = parse_tasks(TASKS);
+
+        assert_eq!(list.total(), 5);
+        assert_eq!(list.completed_count(), 3);
+        let incomplete: Vec<_> = list.incomplete().iter().map(|t| t.label()).collect();
+        assert_eq!(incomplete, vec!["1.2 Wire into commands", "1.2.2 List"]);
+        assert!(list.find("2.1").unwrap().completed);
+        assert!(list.find("9.9").is_none());
+    }
+
+    #[test]
+    fn test_ids_and_loose_tasks() {
+        let list = parse_tasks("- [ ] Unnumbered\n- [ ] 3. Trailing dot\n- [ ] 1.a Bad\n");
+
+        assert_eq!(list.sections.len(), 1);
+        assert_eq!(list.sections[0].number, None);
+        let ids: Vec<_> = list.all().iter().map(|t| t.id.clone()).collect();
+        assert_eq!(ids, vec![None, Some("3".to_string()), Some("1.a".to_string())]);
+
+        assert!(is_well_formed_id("1.2"));
+        assert!(is_well_formed_id("1.2.3"));
+        assert!(!is_well_formed_id("3"));
+        assert!(!is_well_formed_id("1.a"));
+        assert!(!is_well_formed_id("1..2"));
+    }
+
+    #[test]
+    fn test_headings_and_fences() {
+        let source = "## 1. Setup\n#hashtag is not a heading\n- [ ] 1.1 Add setting\n\
+                      ````markdown\n```\n- [ ] 9.9 Inside the fence\n~~~\n````\n\
+                      - [x] 1.2 Add UI\n";
+        let list = parse_tasks(source);
+
+        assert_eq!(list.sections.len(), 1);
+        let labels: Vec<_> = list.all().iter().map(|t| t.label()).collect();
+        assert_eq!(labels, vec!["1.1 Add setting", "1.2 Add UI"]);
+    }
+}
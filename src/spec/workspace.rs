@@ -0,0 +1,184 @@
+//! Cross-file lookups over the specs in a workspace
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use super::paths::{self, delta_spec_path, normalize_name, SpecLocation};
+use super::{parse_spec, DeltaKind, SpecDocument, Span};
+use crate::utils::fs::list_files_recursive;
+
+/// Delta spec entry in a change that touches a requirement
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RequirementChange {
+    /// Change directory name; archived changes keep their date prefix,
+    /// e.g. `2025-01-15-add-2fa`
+    pub change_id: String,
+    pub archived: bool,
+    pub kind: DeltaKind,
+    /// Workspace-relative path of the delta spec
+    pub file: String,
+    /// Requirement heading, or the FROM/TO line of a rename
+    pub span: Span,
+}
+
+/// Workspace-relative paths of source specs, delta specs and change tasks,
+/// sorted; archived changes are left out
+pub fn workspace_files(workspace_path: &Path) -> Result<Vec<String>> {
+    let mut files = Vec::new();
+    for dir in ["specs", "changes"] {
+        let dir = workspace_path.join("openspec").join(dir);
+        if !dir.is_dir() {
+            continue;
+        }
+        for path in list_files_recursive(&dir)? {
+            let Some(file) = relative_path(workspace_path, &path) else {
+                continue;
+            };
+            let wanted = match paths::classify(&file) {
+                SpecLocation::Source { .. } | SpecLocation::Delta { .. } => true,
+                SpecLocation::Change { .. } => file.ends_with("/tasks.md"),
+                SpecLocation::Archived | SpecLocation::Other => false,
+            };
+            if wanted {
+                files.push(file);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// `/` separated path relative to the workspace
+fn relative_path(workspace_path: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(workspace_path).ok()?;
+    let parts: Vec<_> = relative.iter().map(|p| p.to_string_lossy()).collect();
+    Some(parts.join("/"))
+}
+
+/// Every change whose delta spec for `capability` touches requirement `name`
+///
+/// Active changes come first, sorted by ID, followed by archived changes in
+/// archive order.
+pub fn requirement_changes(
+    workspace_path: &Path,
+    capability: &str,
+    name: &str,
+) -> Result<Vec<RequirementChange>> {
+    let name = normalize_name(name);
+    let mut found = Vec::new();
+
+    for (change_id, archived) in change_dirs(workspace_path)? {
+        let file = if archived {
+            delta_spec_path(&format!("archive/{}", change_id), capability)
+        } else {
+            delta_spec_path(&change_id, capability)
+        };
+        let path = workspace_path.join(&file);
+        if !path.is_file() {
+            continue;
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", file))?;
+
+        for (kind, span) in delta_entries(&parse_spec(&content), &name) {
+            found.push(RequirementChange {
+                change_id: change_id.clone(),
+                archived,
+                kind,
+                file: file.clone(),
+                span,
+            });
+        }
+    }
+
+    Ok(found)
+}
+
+/// Delta entries in a change spec that refer to a normalised requirement name
+pub fn delta_entries(doc: &SpecDocument, name: &str) -> Vec<(DeltaKind, Span)> {
+    let mut entries = Vec::new();
+    for delta in &doc.deltas {
+        for requirement in &delta.requirements {
+            if normalize_name(&requirement.name) == name {
+                entries.push((delta.kind, requirement.heading.span));
+            }
+        }
+        for rename in &delta.renames {
+            let target = [&rename.from, &rename.to]
+                .into_iter()
+                .flatten()
+                .find(|target| normalize_name(&target.name) == name);
+            if let Some(target) = target {
+                entries.push((delta.kind, target.span));
+            }
+        }
+    }
+    entries
+}
+
+/// Active change IDs, then archived change directory names, each sorted
+fn change_dirs(workspace_path: &Path) -> Result<Vec<(String, bool)>> {
+    let changes_dir = workspace_path.join("openspec").join("changes");
+    let mut dirs = Vec::new();
+    for (dir, archived) in [(changes_dir.clone(), false), (changes_dir.join("archive"), true)] {
+        if !dir.is_dir() {
+            continue;
+        }
+        let mut names: Vec<String> = fs::read_dir(&dir)
+            .with_context(|| format!("Failed to read {}", dir.display()))?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+            .filter(|name| archived || name != "archive")
+            .collect();
+        names.sort();
+        dirs.extend(names.into_iter().map(|name| (name, archived)));
+    }
+    Ok(dirs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_requirement_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let write = |file: &str, content: &str| {
+            let path = temp_dir.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write(
+            "openspec/changes/add-2fa/specs/auth/spec.md",
+            "## MODIFIED Requirements\n### Requirement: Login\nThe system SHALL ask for a code.\n",
+        );
+        write(
+            "openspec/changes/rename-login/specs/auth/spec.md",
+            "## RENAMED Requirements\n- FROM: `### Requirement: Login`\n- TO: `### Requirement: Sign In`\n",
+        );
+        write(
+            "openspec/changes/archive/2025-01-01-add-login/specs/auth/spec.md",
+            "## ADDED Requirements\n### Requirement:  Login\nThe system SHALL log users in.\n",
+        );
+        write("openspec/changes/add-billing/specs/billing/spec.md", "## ADDED Requirements\n");
+
+        let changes = requirement_changes(temp_dir.path(), "auth", "Login").unwrap();
+        let summary: Vec<_> = changes
+            .iter()
+            .map(|c| (c.change_id.as_str(), c.archived, c.kind, c.span.line_number()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("add-2fa", false, DeltaKind::Modified, 2),
+                ("rename-login", false, DeltaKind::Renamed, 2),
+                ("2025-01-01-add-login", true, DeltaKind::Added, 2),
+            ]
+        );
+        assert_eq!(changes[2].file, "openspec/changes/archive/2025-01-01-add-login/specs/auth/spec.md");
+    }
+}
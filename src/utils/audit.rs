@@ -0,0 +1,108 @@
+//! Read access to audit entries in `.openspec/audit/`
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory holding one JSON file per audit entry
+pub fn audit_dir(workspace_path: &Path) -> PathBuf {
+    workspace_path.join(".openspec").join("audit")
+}
+
+/// The parts of an audit entry needed to link it to changes and tasks
+///
+/// Entries carry more detail (prompt, diffs, signature); unknown fields are
+/// ignored so summaries keep working as the entry format grows.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AuditRecord {
+    pub id: String,
+    /// RFC 3339 time the code was generated
+    #[serde(default)]
+    pub timestamp: String,
+    pub change_id: String,
+    #[serde(default)]
+    pub task_ids: Vec<String>,
+    #[serde(default)]
+    pub llm: Option<AuditLlm>,
+    #[serde(default)]
+    pub acceptance: Option<AuditAcceptance>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AuditLlm {
+    pub provider: String,
+    #[serde(default)]
+    pub model: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AuditAcceptance {
+    pub accepted: bool,
+}
+
+impl AuditRecord {
+    /// Whether this entry generated code for a task in a change
+    pub fn covers_task(&self, change_id: &str, task_id: &str) -> bool {
+        self.change_id == change_id && self.task_ids.iter().any(|id| id == task_id)
+    }
+}
+
+/// Load every audit entry, oldest first
+///
+/// File names start with a timestamp, so name order is time order. Files
+/// that are not valid entries are skipped with a log message.
+pub fn load_audit_records(workspace_path: &Path) -> Result<Vec<AuditRecord>> {
+    let dir = audit_dir(workspace_path);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    let mut records = Vec::new();
+    for path in paths {
+        let parsed = fs::read_to_string(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| Ok(serde_json::from_str(&content)?));
+        match parsed {
+            Ok(record) => records.push(record),
+            Err(e) => eprintln!("[OpenSpec] Skipping audit entry {}: {}", path.display(), e),
+        }
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_audit_records() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = audit_dir(temp_dir.path());
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("20250115_100000-b.json"),
+            r#"{"id": "b", "timestamp": "2025-01-15T10:00:00Z", "change_id": "add-2fa", "task_ids": ["1.2"],
+                "llm": {"provider": "claude", "model": "claude-sonnet-4"}, "acceptance": {"accepted": true},
+                "signature": {"algorithm": "Ed25519"}}"#,
+        )
+        .unwrap();
+        fs::write(dir.join("20250114_090000-a.json"), r#"{"id": "a", "change_id": "add-2fa"}"#).unwrap();
+        fs::write(dir.join("index.txt"), "not an entry").unwrap();
+        fs::write(dir.join("20250116_000000-c.json"), "{").unwrap();
+
+        let records = load_audit_records(temp_dir.path()).unwrap();
+        assert_eq!(records.iter().map(|r| r.id.as_str()).collect::<Vec<_>>(), vec!["a", "b"]);
+        assert!(records[1].covers_task("add-2fa", "1.2"));
+        assert!(!records[0].covers_task("add-2fa", "1.2"));
+        assert_eq!(records[1].llm.as_ref().unwrap().model, "claude-sonnet-4");
+    }
+}
@@ -1,17 +1,35 @@
+use anyhow::{Context, Result};
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use super::layers::LayeredConfig;
+use super::migrate::{migrate, MigrationReport, CONFIG_VERSION};
+use super::schema;
+use crate::validation::rules::find_rule;
+
+/// Workspace configuration file name
+pub const CONFIG_FILE: &str = ".openspec-config.json";
 
 /// Extension configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ExtensionConfig {
+    /// Format version, see [`CONFIG_VERSION`]
+    pub version: u32,
     pub llm: LLMConfig,
     pub validation: ValidationConfig,
     pub audit: AuditConfig,
     pub coverage: CoverageConfig,
     pub workflow: WorkflowConfig,
+    #[serde(default)]
+    pub junit: JunitConfig,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LLMConfig {
     pub default_provider: String,
     pub providers: HashMap<String, ProviderConfig>,
@@ -19,7 +37,7 @@ pub struct LLMConfig {
     pub generation_timeout_seconds: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProviderConfig {
     pub model: String,
     pub api_key_env: Option<String>,
@@ -27,40 +45,169 @@ pub struct ProviderConfig {
     pub endpoint: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ValidationConfig {
     pub enabled: bool,
     pub debounce_ms: u64,
     pub rules: ValidationRules,
+    /// Path-scoped settings; later entries win over earlier ones
+    #[serde(default)]
+    pub overrides: Vec<ValidationOverride>,
+}
+
+/// Validation settings for files matching a set of globs
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ValidationOverride {
+    /// Globs of workspace-relative paths, e.g. `openspec/changes/legacy-*/**`
+    pub files: Vec<String>,
+    /// Turn validation off (or back on) for matching files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Per-rule severity overrides for matching files
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub severity: HashMap<String, RuleSeverity>,
+    /// `files` compiled on first use
+    #[serde(skip)]
+    matcher: OnceLock<GlobSet>,
+}
+
+impl ValidationOverride {
+    /// Override for files matching `files`, changing nothing yet
+    pub fn new(files: Vec<String>) -> Self {
+        Self { files, ..Default::default() }
+    }
+
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Check whether a workspace-relative path matches any of the globs;
+    /// invalid globs never match (they are reported when the config loads)
+    pub fn matches(&self, file: &str) -> bool {
+        self.matcher
+            .get_or_init(|| {
+                let mut builder = GlobSetBuilder::new();
+                for glob in self.files.iter().filter_map(|pattern| override_glob(pattern).ok()) {
+                    builder.add(glob);
+                }
+                builder.build().unwrap_or_else(|_| GlobSet::empty())
+            })
+            .is_match(file)
+    }
+}
+
+/// Compile an override glob; `*` and `?` don't match `/`, as in `.gitignore`
+pub(crate) fn override_glob(pattern: &str) -> std::result::Result<Glob, globset::Error> {
+    GlobBuilder::new(pattern).literal_separator(true).build()
+}
+
+impl ValidationConfig {
+    /// Effective rules for a workspace-relative path, or `None` when
+    /// validation is turned off for it
+    pub fn rules_for(&self, file: &str) -> Option<ValidationRules> {
+        let mut enabled = self.enabled;
+        let mut rules = self.rules.clone();
+
+        for entry in self.overrides.iter().filter(|o| o.matches(file)) {
+            if let Some(value) = entry.enabled {
+                enabled = value;
+            }
+            for (key, level) in &entry.severity {
+                // Drop an equivalent key (ID vs name) so the override takes effect
+                if let Some(rule) = find_rule(key) {
+                    rules.severity.retain(|existing, _| !rule.matches(existing));
+                }
+                rules.severity.insert(key.clone(), *level);
+            }
+        }
+
+        enabled.then_some(rules)
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ValidationRules {
     pub require_scenarios: bool,
     pub require_shall_must: bool,
+    /// Hard limit: larger specs are reported as errors
     pub max_spec_size_kb: usize,
+    /// Soft limit: larger specs are reported as warnings
+    #[serde(default = "default_warn_spec_size_kb")]
+    pub warn_spec_size_kb: usize,
+    /// Hard limit on the markdown files of a change directory combined
+    #[serde(default = "default_max_change_size_kb")]
+    pub max_change_size_kb: usize,
+    /// Soft limit on the markdown files of a change directory combined
+    #[serde(default = "default_warn_change_size_kb")]
+    pub warn_change_size_kb: usize,
+    /// Per-rule severity overrides, keyed by rule ID (`OS001`) or name (`missing-scenario`)
+    #[serde(default)]
+    pub severity: HashMap<String, RuleSeverity>,
+}
+
+fn default_warn_spec_size_kb() -> usize {
+    256
+}
+
+fn default_max_change_size_kb() -> usize {
+    4096
+}
+
+fn default_warn_change_size_kb() -> usize {
+    1024
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Severity override for a validation rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+    Off,
+    Hint,
+    #[serde(alias = "information")]
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AuditConfig {
     pub enabled: bool,
     pub retention_days: u32,
     pub signature_required: bool,
+    /// One of `json`, `csv` or `report`
+    #[schemars(schema_with = "schema::export_format_schema")]
     pub export_format: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CoverageConfig {
     pub exclude_patterns: Vec<String>,
+    /// Percentage between 0 and 100
+    #[schemars(range(min = 0, max = 100))]
     pub minimum_coverage_percent: f32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct WorkflowConfig {
     pub auto_archive_on_complete: bool,
     pub require_all_tasks_complete: bool,
 }
 
+/// Settings for `--format junit` reports
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct JunitConfig {
+    /// Emit a test case per task in `tasks.md`; when off, task findings are
+    /// reported under the file's "File checks" case
+    pub include_tasks: bool,
+}
+
+impl Default for JunitConfig {
+    fn default() -> Self {
+        Self { include_tasks: true }
+    }
+}
+
 impl Default for ExtensionConfig {
     fn default() -> Self {
         let mut providers = HashMap::new();
@@ -96,6 +243,7 @@ impl Default for ExtensionConfig {
         );
 
         Self {
+            version: CONFIG_VERSION,
             llm: LLMConfig {
                 default_provider: "claude".to_string(),
                 providers,
@@ -109,7 +257,12 @@ impl Default for ExtensionConfig {
                     require_scenarios: true,
                     require_shall_must: true,
                     max_spec_size_kb: 1024,
+                    warn_spec_size_kb: default_warn_spec_size_kb(),
+                    max_change_size_kb: default_max_change_size_kb(),
+                    warn_change_size_kb: default_warn_change_size_kb(),
+                    severity: HashMap::new(),
                 },
+                overrides: Vec::new(),
             },
             audit: AuditConfig {
                 enabled: true,
@@ -129,21 +282,246 @@ impl Default for ExtensionConfig {
                 auto_archive_on_complete: false,
                 require_all_tasks_complete: true,
             },
+            junit: JunitConfig::default(),
         }
     }
 }
 
 impl ExtensionConfig {
-    /// Load configuration from file or return default
-    pub fn load_or_default() -> Self {
-        // For Phase 1, just return defaults
-        // In future phases, read from .openspec-config.json
-        Self::default()
+    /// Path of the configuration file in a workspace
+    pub fn path(workspace_path: &Path) -> PathBuf {
+        workspace_path.join(CONFIG_FILE)
+    }
+
+    /// Load `.openspec-config.json` from a workspace, merged over the defaults
+    ///
+    /// Missing keys keep their default values, so a file may set just the
+    /// settings it cares about. A missing file yields the defaults.
+    pub fn load(workspace_path: &Path) -> Result<Self> {
+        let path = Self::path(workspace_path);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let mut overrides: Value = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid JSON in {}", path.display()))?;
+        migrate_layer(&mut overrides, &path)?;
+        Self::from_overrides(overrides)
+            .with_context(|| format!("Invalid configuration in {}", path.display()))
+    }
+
+    /// Merge a partial configuration document over the defaults
+    pub fn from_overrides(overrides: Value) -> Result<Self> {
+        let mut merged = serde_json::to_value(Self::default())?;
+        merge_json(&mut merged, overrides);
+        from_document(merged)
+    }
+
+    /// Load configuration from every layer or return default
+    ///
+    /// Layers are the user file, the workspace file and `OPENSPEC_*`
+    /// environment variables (see [`LayeredConfig`]). Unreadable or invalid
+    /// layers are logged and ignored.
+    pub fn load_or_default(workspace_path: &Path) -> Self {
+        LayeredConfig::load(workspace_path).map(|layered| layered.config).unwrap_or_else(|e| {
+            eprintln!("[OpenSpec] {:#}; using default configuration", e);
+            Self::default()
+        })
     }
 
     /// Save configuration to file
-    pub fn save(&self, _path: &std::path::Path) -> anyhow::Result<()> {
-        // Placeholder for future implementation
-        Ok(())
+    ///
+    /// An existing file is migrated to the current version first; keys in it
+    /// that this version doesn't know about are kept as they are. Only keys
+    /// already in the file or differing from the defaults are written, so
+    /// lower layers aren't shadowed by pinned defaults.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut document = if path.exists() {
+            let content = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let mut document = serde_json::from_str(&content)
+                .with_context(|| format!("Invalid JSON in {}", path.display()))?;
+            migrate(&mut document)
+                .with_context(|| format!("Failed to migrate {}", path.display()))?;
+            document
+        } else {
+            Value::Object(Default::default())
+        };
+        let changes = persisted_keys(
+            &serde_json::to_value(self)?,
+            &serde_json::to_value(Self::default())?,
+            Some(&document),
+        );
+        if let Some(changes) = changes {
+            merge_json(&mut document, changes);
+        }
+
+        std::fs::write(path, serde_json::to_string_pretty(&document)? + "\n")
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// Migrate a config file's document in memory, noting when the file is outdated
+pub(crate) fn migrate_layer(document: &mut Value, path: &Path) -> Result<MigrationReport> {
+    let report = migrate(document).with_context(|| format!("Failed to migrate {}", path.display()))?;
+    if !report.is_current() {
+        eprintln!(
+            "[OpenSpec] {} is config version {}; migrated to {} in memory. Run 'openspec:migrate-config --write' to update the file.",
+            path.display(),
+            report.from_version,
+            report.to_version
+        );
+    }
+    Ok(report)
+}
+
+/// Deserialize a complete configuration document and check its values
+///
+/// Errors name the offending key, e.g. `llm.default_provider`.
+pub(crate) fn from_document(document: Value) -> Result<ExtensionConfig> {
+    let config: ExtensionConfig = serde_path_to_error::deserialize(document).map_err(|e| {
+        anyhow::anyhow!("Invalid value for '{}': {}", e.path(), e.inner())
+    })?;
+
+    let issues = schema::check_config(&config);
+    if !issues.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Invalid configuration:\n{}",
+            issues.iter().map(|i| format!("- {}", i)).collect::<Vec<_>>().join("\n")
+        ));
+    }
+    Ok(config)
+}
+
+/// Parts of `value` that are present in `existing` or differ from `default`
+fn persisted_keys(value: &Value, default: &Value, existing: Option<&Value>) -> Option<Value> {
+    match value {
+        Value::Object(map) => {
+            let kept: Map<String, Value> = map
+                .iter()
+                .filter_map(|(key, child)| {
+                    let default = default.get(key).unwrap_or(&Value::Null);
+                    let existing = existing.and_then(|e| e.get(key));
+                    Some((key.clone(), persisted_keys(child, default, existing)?))
+                })
+                .collect();
+            (!kept.is_empty() || existing.is_some()).then_some(Value::Object(kept))
+        }
+        _ => (existing.is_some() || value != default).then(|| value.clone()),
+    }
+}
+
+/// Recursively merge `overlay` into `base`; non-object values replace
+pub(crate) fn merge_json(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_json(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_partial_file_merges_over_defaults() {
+        let workspace = TempDir::new().unwrap();
+        std::fs::write(
+            ExtensionConfig::path(workspace.path()),
+            r#"{"coverage": {"minimum_coverage_percent": 80}, "llm": {"providers": {"ollama": {"model": "llama3"}}}}"#,
+        )
+        .unwrap();
+
+        let config = ExtensionConfig::load(workspace.path()).unwrap();
+        assert_eq!(config.coverage.minimum_coverage_percent, 80.0);
+        assert_eq!(config.coverage.exclude_patterns.len(), 3);
+        assert_eq!(config.llm.providers["ollama"].model, "llama3");
+        assert_eq!(config.llm.providers["ollama"].max_tokens, 4000);
+        assert!(config.validation.enabled);
+    }
+
+    #[test]
+    fn test_invalid_file_falls_back_to_defaults() {
+        let workspace = TempDir::new().unwrap();
+        assert_eq!(ExtensionConfig::load(workspace.path()).unwrap().validation.debounce_ms, 500);
+
+        std::fs::write(ExtensionConfig::path(workspace.path()), r#"{"validation": {"debounce_ms": "soon"}}"#).unwrap();
+        assert!(ExtensionConfig::load(workspace.path()).is_err());
+        assert_eq!(ExtensionConfig::load_or_default(workspace.path()).validation.debounce_ms, 500);
+    }
+
+    #[test]
+    fn test_path_overrides() {
+        let mut validation = ExtensionConfig::default().validation;
+        validation.rules.severity.insert("missing-scenario".to_string(), RuleSeverity::Error);
+        validation.overrides = vec![
+            ValidationOverride {
+                files: vec!["openspec/changes/legacy-*/**".to_string()],
+                severity: HashMap::from([("OS001".to_string(), RuleSeverity::Off)]),
+                ..Default::default()
+            },
+            ValidationOverride {
+                files: vec!["openspec/specs/vendor/**".to_string()],
+                enabled: Some(false),
+                ..Default::default()
+            },
+        ];
+
+        let legacy = validation.rules_for("openspec/changes/legacy-auth/specs/auth/spec.md").unwrap();
+        assert_eq!(legacy.severity.len(), 1);
+        assert_eq!(legacy.severity["OS001"], RuleSeverity::Off);
+        assert_eq!(validation.rules_for("openspec/specs/auth/spec.md").unwrap().severity["missing-scenario"], RuleSeverity::Error);
+        assert!(validation.rules_for("openspec/specs/vendor/stripe/spec.md").is_none());
+
+        // `*` stays within one directory
+        let shallow = ValidationOverride::new(vec!["openspec/specs/*".to_string()]);
+        assert!(shallow.matches("openspec/specs/README.md"));
+        assert!(!shallow.matches("openspec/specs/auth/spec.md"));
+
+        // Gradual adoption: off by default, on for one capability
+        validation.enabled = false;
+        validation.overrides = vec![ValidationOverride {
+            files: vec!["openspec/specs/auth/**".to_string()],
+            enabled: Some(true),
+            ..Default::default()
+        }];
+        assert!(validation.rules_for("openspec/specs/auth/spec.md").is_some());
+        assert!(validation.rules_for("openspec/specs/billing/spec.md").is_none());
+    }
+
+    #[test]
+    fn test_save_keeps_unknown_keys() {
+        let workspace = TempDir::new().unwrap();
+        let path = ExtensionConfig::path(workspace.path());
+        std::fs::write(&path, r#"{"team": "payments", "audit": {"retention_days": 30, "archive_bucket": "s3://x"}}"#).unwrap();
+
+        let mut config = ExtensionConfig::load(workspace.path()).unwrap();
+        assert_eq!(config.audit.retention_days, 30);
+        config.workflow.auto_archive_on_complete = true;
+        config.save(&path).unwrap();
+
+        let saved: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved["team"], "payments");
+        assert_eq!(saved["audit"]["archive_bucket"], "s3://x");
+        assert_eq!(saved["audit"]["retention_days"], 30);
+        assert_eq!(saved["workflow"]["auto_archive_on_complete"], true);
+        // Defaults that weren't in the file stay out of it
+        assert_eq!(saved["audit"].as_object().unwrap().len(), 2);
+        assert_eq!(saved["workflow"].as_object().unwrap().len(), 1);
+        assert!(saved.get("llm").is_none());
+        assert!(saved.get("validation").is_none());
+        assert!(ExtensionConfig::load(workspace.path()).unwrap().workflow.auto_archive_on_complete);
     }
 }
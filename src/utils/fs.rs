@@ -1,9 +1,9 @@
+//! File system utility functions
+
 use std::path::{Path, PathBuf};
 use std::fs;
 use anyhow::{Result, Context};
 
-/// File system utility functions
-
 /// Check if a directory exists
 pub fn dir_exists(path: &Path) -> bool {
     path.exists() && path.is_dir()
@@ -64,6 +64,25 @@ pub fn list_files(path: &Path) -> Result<Vec<PathBuf>> {
     Ok(files)
 }
 
+/// List all files under a directory (recursive), sorted by path
+pub fn list_files_recursive(path: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.is_dir() {
+            files.extend(list_files_recursive(&path)?);
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
 /// Get file size in bytes
 pub fn file_size(path: &Path) -> Result<u64> {
     Ok(fs::metadata(path)?.len())
@@ -108,5 +127,11 @@ mod tests {
         // List subdirectories
         let dirs = list_subdirectories(temp_dir.path()).unwrap();
         assert_eq!(dirs.len(), 1);
+
+        // List nested files
+        write_file(&sub_dir.join("b.md"), "b").unwrap();
+        write_file(&temp_dir.path().join("a.md"), "a").unwrap();
+        let files = list_files_recursive(temp_dir.path()).unwrap();
+        assert_eq!(files, vec![temp_dir.path().join("a.md"), sub_dir.join("b.md")]);
     }
 }
@@ -0,0 +1,322 @@
+//! Layered configuration: defaults, user file, workspace file, environment
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use super::config::{from_document, merge_json, migrate_layer, ExtensionConfig};
+
+/// Prefix of environment variables that override configuration values
+pub const ENV_PREFIX: &str = "OPENSPEC_";
+
+/// Separator between key segments in environment variable names
+const ENV_SEPARATOR: &str = "__";
+
+/// Config keys whose entries are user-defined, so new entries may be created
+const MAP_KEYS: &[&str] = &["llm.providers", "validation.rules.severity"];
+
+/// Source of a configuration value, lowest precedence first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigLayer {
+    /// Built-in defaults
+    Default,
+    /// Per-user file, e.g. `~/.config/openspec/config.json`
+    User,
+    /// Workspace `.openspec-config.json`
+    Workspace,
+    /// `OPENSPEC_*` environment variables
+    Environment,
+}
+
+impl ConfigLayer {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::User => "user",
+            Self::Workspace => "workspace",
+            Self::Environment => "environment",
+        }
+    }
+}
+
+/// Effective configuration plus the layer each value came from
+#[derive(Debug, Clone)]
+pub struct LayeredConfig {
+    pub config: ExtensionConfig,
+    /// Merged document, used to report effective values
+    document: Value,
+    /// Dotted leaf key (`validation.debounce_ms`) to the layer that set it
+    sources: BTreeMap<String, ConfigLayer>,
+}
+
+impl LayeredConfig {
+    /// Load every layer for a workspace, using the process environment
+    ///
+    /// A layer that can't be read or holds invalid values is logged and
+    /// skipped; the remaining layers still apply. Each `OPENSPEC_*` variable
+    /// counts as its own layer.
+    pub fn load(workspace_path: &Path) -> Result<Self> {
+        let mut layers = Vec::new();
+
+        let files = user_config_path()
+            .map(|path| (ConfigLayer::User, path))
+            .into_iter()
+            .chain([(ConfigLayer::Workspace, ExtensionConfig::path(workspace_path))]);
+        for (layer, path) in files {
+            match read_layer(&path) {
+                Ok(Some(document)) => layers.push((layer, document)),
+                Ok(None) => {}
+                Err(e) => eprintln!("[OpenSpec] {:#}; ignoring this file", e),
+            }
+        }
+        for document in env_overrides(std::env::vars())? {
+            layers.push((ConfigLayer::Environment, document));
+        }
+
+        Self::from_layers(layers)
+    }
+
+    /// Merge layers, in increasing precedence, over the built-in defaults
+    ///
+    /// A layer whose values don't validate on top of the layers before it is
+    /// logged and left out.
+    pub fn from_layers(layers: Vec<(ConfigLayer, Value)>) -> Result<Self> {
+        let mut document = serde_json::to_value(ExtensionConfig::default())?;
+        let mut sources = BTreeMap::new();
+        record_leaves(&document, "", ConfigLayer::Default, &mut sources);
+
+        for (layer, overlay) in layers {
+            let mut merged = document.clone();
+            merge_json(&mut merged, overlay.clone());
+            if let Err(e) = from_document(merged.clone()) {
+                eprintln!("[OpenSpec] Ignoring {} configuration: {:#}", layer.label(), e);
+                continue;
+            }
+            record_leaves(&overlay, "", layer, &mut sources);
+            document = merged;
+        }
+
+        let config = from_document(document.clone())?;
+        Ok(Self { config, document, sources })
+    }
+
+    /// Layer that set a dotted key, e.g. `validation.rules.require_scenarios`
+    pub fn source_of(&self, key: &str) -> Option<ConfigLayer> {
+        self.sources.get(key).copied()
+    }
+
+    /// Every effective leaf value with its source, sorted by key
+    pub fn entries(&self) -> Vec<ConfigEntry> {
+        self.sources
+            .iter()
+            .filter_map(|(key, layer)| {
+                let pointer = format!("/{}", key.replace('.', "/"));
+                // Map keys that gained entries in a later layer are reported per entry
+                let value = self.document.pointer(&pointer)?;
+                if value.as_object().is_some_and(|map| !map.is_empty()) {
+                    return None;
+                }
+                Some(ConfigEntry {
+                    key: key.clone(),
+                    value: value.clone(),
+                    source: *layer,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Effective value of a single configuration key
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigEntry {
+    pub key: String,
+    pub value: Value,
+    pub source: ConfigLayer,
+}
+
+/// Per-user config file
+///
+/// `$XDG_CONFIG_HOME/openspec/config.json`, falling back to `~/.config` and
+/// then `%APPDATA%` on Windows.
+pub fn user_config_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))?;
+    Some(base.join("openspec").join("config.json"))
+}
+
+fn read_layer(path: &Path) -> Result<Option<Value>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let mut document = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid JSON in {}", path.display()))?;
+    migrate_layer(&mut document, path)?;
+    Ok(Some(document))
+}
+
+/// Build one override document per `OPENSPEC_*` variable
+///
+/// `OPENSPEC_VALIDATION__RULES__REQUIRE_SCENARIOS=true` sets
+/// `validation.rules.require_scenarios`. Values are parsed as JSON when
+/// possible (`true`, `200`, `["a"]`) and used as strings otherwise.
+/// Variables naming unknown keys are logged and skipped.
+pub fn env_overrides(vars: impl IntoIterator<Item = (String, String)>) -> Result<Vec<Value>> {
+    let defaults = serde_json::to_value(ExtensionConfig::default())?;
+    let mut overrides = Vec::new();
+
+    for (name, raw) in vars {
+        let Some(rest) = name.strip_prefix(ENV_PREFIX) else {
+            continue;
+        };
+        if !rest.contains(ENV_SEPARATOR) {
+            continue;
+        }
+
+        let segments: Vec<String> = rest.split(ENV_SEPARATOR).map(|s| s.to_ascii_lowercase()).collect();
+        let key = segments.join(".");
+        if !is_known_key(&defaults, &segments) {
+            eprintln!("[OpenSpec] Ignoring {}: unknown configuration key '{}'", name, key);
+            continue;
+        }
+
+        let value = serde_json::from_str(&raw).unwrap_or(Value::String(raw));
+        let mut document = Value::Object(Map::new());
+        let mut target = &mut document;
+        for segment in &segments {
+            if !target.is_object() {
+                *target = Value::Object(Map::new());
+            }
+            let Value::Object(map) = target else {
+                unreachable!("target was just made an object");
+            };
+            target = map
+                .entry(segment.clone())
+                .or_insert_with(|| Value::Object(Map::new()));
+        }
+        *target = value;
+        overrides.push(document);
+    }
+
+    Ok(overrides)
+}
+
+/// A key is known if it exists in the defaults or sits inside a map key
+fn is_known_key(defaults: &Value, segments: &[String]) -> bool {
+    let pointer = format!("/{}", segments.join("/"));
+    if defaults.pointer(&pointer).is_some() {
+        return true;
+    }
+    MAP_KEYS.iter().any(|map| {
+        let prefix: Vec<&str> = map.split('.').collect();
+        segments.len() > prefix.len() && segments.iter().zip(&prefix).all(|(a, b)| a == b)
+    })
+}
+
+/// Record `layer` as the source of every leaf value in `value`
+fn record_leaves(
+    value: &Value,
+    prefix: &str,
+    layer: ConfigLayer,
+    sources: &mut BTreeMap<String, ConfigLayer>,
+) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                record_leaves(child, &path, layer, sources);
+            }
+        }
+        Value::Object(_) if prefix.is_empty() => {}
+        _ => {
+            sources.insert(prefix.to_string(), layer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_layer_precedence_and_sources() {
+        let env = env_overrides(vars(&[
+            ("OPENSPEC_VALIDATION__RULES__SEVERITY__OS001", "error"),
+            ("OPENSPEC_VALIDATION__DEBOUNCE_MS", "50"),
+            ("OPENSPEC_CONFIG_HOME", "/ignored"),
+            ("PATH", "/usr/bin"),
+        ]))
+        .unwrap();
+        let mut layers = vec![
+            (ConfigLayer::User, json!({"validation": {"debounce_ms": 300}, "audit": {"retention_days": 90}})),
+            (ConfigLayer::Workspace, json!({"audit": {"retention_days": 365}})),
+        ];
+        layers.extend(env.into_iter().map(|document| (ConfigLayer::Environment, document)));
+        let layered = LayeredConfig::from_layers(layers).unwrap();
+
+        assert_eq!(layered.config.validation.debounce_ms, 50);
+        assert_eq!(layered.config.audit.retention_days, 365);
+        assert_eq!(layered.source_of("validation.debounce_ms"), Some(ConfigLayer::Environment));
+        assert_eq!(layered.source_of("audit.retention_days"), Some(ConfigLayer::Workspace));
+        assert_eq!(layered.source_of("audit.enabled"), Some(ConfigLayer::Default));
+        assert_eq!(
+            layered.source_of("validation.rules.severity.os001"),
+            Some(ConfigLayer::Environment)
+        );
+
+        let entry = layered
+            .entries()
+            .into_iter()
+            .find(|e| e.key == "llm.fallback_chain")
+            .unwrap();
+        assert_eq!(entry.value, json!(["claude", "gpt-4"]));
+        assert_eq!(entry.source, ConfigLayer::Default);
+    }
+
+    #[test]
+    fn test_env_overrides_parse_values_and_skip_unknown_keys() {
+        let env = env_overrides(vars(&[
+            ("OPENSPEC_LLM__DEFAULT_PROVIDER", "ollama"),
+            ("OPENSPEC_VALIDATION__STRICT", "true"),
+            ("OPENSPEC_VALIDATION__RULES__REQUIRE_SCENARIOS", "false"),
+        ]))
+        .unwrap();
+        assert_eq!(env, vec![
+            json!({"llm": {"default_provider": "ollama"}}),
+            json!({"validation": {"rules": {"require_scenarios": false}}}),
+        ]);
+    }
+
+    #[test]
+    fn test_bad_layers_leave_the_others_intact() {
+        let env = env_overrides(vars(&[
+            ("OPENSPEC_CACHE__DIR", "x"),
+            ("OPENSPEC_LLM__DEFAULT_PROVIDER", "nonexistent"),
+            ("OPENSPEC_AUDIT__RETENTION_DAYS", "30"),
+        ]))
+        .unwrap();
+        let mut layers = vec![
+            (ConfigLayer::User, json!({"validation": {"debounce_ms": "slow"}})),
+            (ConfigLayer::Workspace, json!({"validation": {"debounce_ms": 900}})),
+        ];
+        layers.extend(env.into_iter().map(|document| (ConfigLayer::Environment, document)));
+        let layered = LayeredConfig::from_layers(layers).unwrap();
+
+        assert_eq!(layered.config.validation.debounce_ms, 900);
+        assert_eq!(layered.source_of("validation.debounce_ms"), Some(ConfigLayer::Workspace));
+        assert_eq!(layered.config.llm.default_provider, ExtensionConfig::default().llm.default_provider);
+        assert_eq!(layered.source_of("llm.default_provider"), Some(ConfigLayer::Default));
+        assert_eq!(layered.config.audit.retention_days, 30);
+    }
+}
@@ -0,0 +1,143 @@
+//! Config versioning and step-by-step migration of older documents
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Current `version` of the configuration format
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Single upgrade step from `from` to `from + 1`
+pub struct Migration {
+    pub from: u32,
+    /// Applies the step and describes each change it made
+    pub apply: fn(&mut Map<String, Value>) -> Vec<String>,
+}
+
+/// Every upgrade step, in order
+pub const MIGRATIONS: &[Migration] = &[Migration {
+    // Versioning introduced; the format is otherwise unchanged
+    from: 0,
+    apply: |_| Vec::new(),
+}];
+
+/// What a migration run changed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MigrationReport {
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Human-readable description of each change, in order
+    pub changes: Vec<String>,
+}
+
+impl MigrationReport {
+    /// Whether the document was already current
+    pub fn is_current(&self) -> bool {
+        self.from_version == self.to_version
+    }
+}
+
+/// Upgrade a configuration document to [`CONFIG_VERSION`] in place
+///
+/// Documents without a `version` key predate versioning and are treated as
+/// version 0.
+pub fn migrate(document: &mut Value) -> Result<MigrationReport> {
+    run(document, MIGRATIONS, CONFIG_VERSION)
+}
+
+fn run(document: &mut Value, migrations: &[Migration], target: u32) -> Result<MigrationReport> {
+    let Value::Object(map) = document else {
+        return Err(anyhow::anyhow!("Configuration must be a JSON object"));
+    };
+
+    let from_version = match map.get("version") {
+        None => 0,
+        Some(value) => value
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| anyhow::anyhow!("Invalid value for 'version': expected a non-negative integer"))?,
+    };
+    if from_version > target {
+        return Err(anyhow::anyhow!(
+            "Configuration version {} is newer than this extension supports ({}). Update the extension.",
+            from_version,
+            target
+        ));
+    }
+
+    let mut changes = Vec::new();
+    for version in from_version..target {
+        let step = migrations
+            .iter()
+            .find(|m| m.from == version)
+            .ok_or_else(|| anyhow::anyhow!("No migration from configuration version {}", version))?;
+        changes.extend((step.apply)(map));
+        changes.push(format!("Set version {} -> {}", version, version + 1));
+        map.insert("version".to_string(), Value::from(version + 1));
+    }
+
+    Ok(MigrationReport {
+        from_version,
+        to_version: target,
+        changes,
+    })
+}
+
+/// Move `section.from` to `section.to`, unless `to` is already set
+///
+/// Helper for migrations that rename a key.
+pub fn rename_key(map: &mut Map<String, Value>, section: &str, from: &str, to: &str) -> Option<String> {
+    let section_map = map.get_mut(section)?.as_object_mut()?;
+    let value = section_map.remove(from)?;
+    if section_map.contains_key(to) {
+        return Some(format!("Removed {}.{} (superseded by {}.{})", section, from, section, to));
+    }
+    section_map.insert(to.to_string(), value);
+    Some(format!("Renamed {}.{} to {}.{}", section, from, section, to))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const STEPS: &[Migration] = &[
+        Migration {
+            from: 0,
+            apply: |_| Vec::new(),
+        },
+        Migration {
+            from: 1,
+            apply: |map| rename_key(map, "validation", "debounce", "debounce_ms").into_iter().collect(),
+        },
+    ];
+
+    #[test]
+    fn test_migrates_step_by_step() {
+        let mut document = json!({"validation": {"debounce": 200}});
+        let report = run(&mut document, STEPS, 2).unwrap();
+
+        assert_eq!(report.from_version, 0);
+        assert_eq!(
+            report.changes,
+            vec![
+                "Set version 0 -> 1",
+                "Renamed validation.debounce to validation.debounce_ms",
+                "Set version 1 -> 2",
+            ]
+        );
+        assert_eq!(document, json!({"version": 2, "validation": {"debounce_ms": 200}}));
+
+        let report = run(&mut document, STEPS, 2).unwrap();
+        assert!(report.is_current());
+        assert!(report.changes.is_empty());
+    }
+
+    #[test]
+    fn test_rejects_newer_or_invalid_versions() {
+        let err = migrate(&mut json!({"version": CONFIG_VERSION + 1})).unwrap_err();
+        assert!(err.to_string().contains("newer than this extension supports"));
+        assert!(migrate(&mut json!({"version": "one"})).is_err());
+        assert!(migrate(&mut json!([])).is_err());
+    }
+}
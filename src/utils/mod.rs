@@ -1,3 +1,7 @@
+pub mod audit;
 pub mod config;
 pub mod errors;
 pub mod fs;
+pub mod layers;
+pub mod migrate;
+pub mod schema;
@@ -0,0 +1,238 @@
+//! Semantic config checks and JSON Schema export
+
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{InstanceType, Schema, SchemaObject};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+
+use super::config::{override_glob, ExtensionConfig, RuleSeverity};
+use crate::validation::rules::{find_rule, ALL_RULES};
+
+/// Values accepted by `audit.export_format`
+pub const EXPORT_FORMATS: &[&str] = &["json", "csv", "report"];
+
+/// Problem with a configuration value
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigIssue {
+    /// Dotted key, e.g. `llm.fallback_chain[1]`
+    pub key: String,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.message)
+    }
+}
+
+/// Check values that deserialize fine but make no sense together
+pub fn check_config(config: &ExtensionConfig) -> Vec<ConfigIssue> {
+    let mut issues = Vec::new();
+    let mut issue = |key: String, message: String| issues.push(ConfigIssue { key, message });
+
+    let mut providers: Vec<&str> = config.llm.providers.keys().map(|k| k.as_str()).collect();
+    providers.sort_unstable();
+    let known = providers.join(", ");
+
+    if !config.llm.providers.contains_key(&config.llm.default_provider) {
+        issue(
+            "llm.default_provider".to_string(),
+            format!(
+                "provider '{}' is not defined in llm.providers (known: {})",
+                config.llm.default_provider, known
+            ),
+        );
+    }
+    for (i, name) in config.llm.fallback_chain.iter().enumerate() {
+        if !config.llm.providers.contains_key(name) {
+            issue(
+                format!("llm.fallback_chain[{}]", i),
+                format!("provider '{}' is not defined in llm.providers (known: {})", name, known),
+            );
+        }
+    }
+    if config.llm.generation_timeout_seconds == 0 {
+        issue(
+            "llm.generation_timeout_seconds".to_string(),
+            "must be greater than 0".to_string(),
+        );
+    }
+    for name in &providers {
+        let provider = &config.llm.providers[*name];
+        if provider.model.trim().is_empty() {
+            issue(format!("llm.providers.{}.model", name), "must not be empty".to_string());
+        }
+        if provider.max_tokens == 0 {
+            issue(format!("llm.providers.{}.max_tokens", name), "must be greater than 0".to_string());
+        }
+    }
+
+    let rules = &config.validation.rules;
+    if rules.warn_spec_size_kb > rules.max_spec_size_kb {
+        issue(
+            "validation.rules.warn_spec_size_kb".to_string(),
+            format!(
+                "soft limit ({} KB) is above max_spec_size_kb ({} KB)",
+                rules.warn_spec_size_kb, rules.max_spec_size_kb
+            ),
+        );
+    }
+    if rules.warn_change_size_kb > rules.max_change_size_kb {
+        issue(
+            "validation.rules.warn_change_size_kb".to_string(),
+            format!(
+                "soft limit ({} KB) is above max_change_size_kb ({} KB)",
+                rules.warn_change_size_kb, rules.max_change_size_kb
+            ),
+        );
+    }
+    check_severity_keys(&rules.severity, "validation.rules.severity", &mut issue);
+    for (i, entry) in config.validation.overrides.iter().enumerate() {
+        if entry.files.is_empty() {
+            issue(format!("validation.overrides[{}].files", i), "must list at least one glob".to_string());
+        }
+        for (j, pattern) in entry.files.iter().enumerate() {
+            if let Err(e) = override_glob(pattern) {
+                issue(format!("validation.overrides[{}].files[{}]", i, j), format!("invalid glob: {}", e.kind()));
+            }
+        }
+        check_severity_keys(&entry.severity, &format!("validation.overrides[{}].severity", i), &mut issue);
+    }
+
+    let coverage = config.coverage.minimum_coverage_percent;
+    if !(0.0..=100.0).contains(&coverage) {
+        issue(
+            "coverage.minimum_coverage_percent".to_string(),
+            format!("{} is outside 0-100", coverage),
+        );
+    }
+
+    if !EXPORT_FORMATS.contains(&config.audit.export_format.as_str()) {
+        issue(
+            "audit.export_format".to_string(),
+            format!(
+                "'{}' is not supported (expected one of: {})",
+                config.audit.export_format,
+                EXPORT_FORMATS.join(", ")
+            ),
+        );
+    }
+
+    issues
+}
+
+/// Flag severity keys that look like built-in rule IDs but aren't one
+///
+/// Other keys may name custom rules, which are loaded separately.
+fn check_severity_keys(
+    severity: &HashMap<String, RuleSeverity>,
+    prefix: &str,
+    issue: &mut impl FnMut(String, String),
+) {
+    let mut keys: Vec<&String> = severity.keys().collect();
+    keys.sort_unstable();
+    for key in keys {
+        let builtin_id = key.get(..2).is_some_and(|p| p.eq_ignore_ascii_case("OS"));
+        if builtin_id && find_rule(key).is_none() {
+            issue(
+                format!("{}.{}", prefix, key),
+                format!(
+                    "unknown built-in rule (known IDs: {} to {})",
+                    ALL_RULES[0].id,
+                    ALL_RULES[ALL_RULES.len() - 1].id
+                ),
+            );
+        }
+    }
+}
+
+/// JSON Schema for `.openspec-config.json`
+///
+/// Every key is optional, since files are merged over the defaults.
+pub fn config_schema() -> Value {
+    let mut schema = serde_json::to_value(schemars::schema_for!(ExtensionConfig))
+        .expect("config schema serializes");
+    strip_required(&mut schema);
+    if let Value::Object(map) = &mut schema {
+        map.insert("title".to_string(), Value::String("OpenSpec configuration".to_string()));
+    }
+    schema
+}
+
+/// Schema for `audit.export_format`, a string limited to [`EXPORT_FORMATS`]
+pub(crate) fn export_format_schema(_: &mut SchemaGenerator) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        enum_values: Some(EXPORT_FORMATS.iter().map(|f| Value::String(f.to_string())).collect()),
+        ..Default::default()
+    }
+    .into()
+}
+
+fn strip_required(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.remove("required");
+            map.values_mut().for_each(strip_required);
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_required),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_check_config_points_at_keys() {
+        assert!(check_config(&ExtensionConfig::default()).is_empty());
+
+        let mut config = ExtensionConfig::default();
+        config.llm.default_provider = "gemini".to_string();
+        config.llm.fallback_chain.push("mistral".to_string());
+        config.coverage.minimum_coverage_percent = 120.0;
+        config.audit.export_format = "xml".to_string();
+        config.validation.rules.severity.insert("OS999".to_string(), RuleSeverity::Off);
+        config
+            .validation
+            .overrides
+            .push(crate::utils::config::ValidationOverride::new(vec!["openspec/{a,b".to_string()]));
+
+        let keys: Vec<_> = check_config(&config).into_iter().map(|i| i.key).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "llm.default_provider",
+                "llm.fallback_chain[2]",
+                "validation.rules.severity.OS999",
+                "validation.overrides[0].files[0]",
+                "coverage.minimum_coverage_percent",
+                "audit.export_format",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_errors_name_the_key() {
+        let err = ExtensionConfig::from_overrides(json!({"validation": {"debounce_ms": "soon"}})).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid value for 'validation.debounce_ms'"));
+
+        let err = ExtensionConfig::from_overrides(json!({"llm": {"default_provider": "gemini"}})).unwrap_err();
+        assert!(err.to_string().contains("- llm.default_provider: provider 'gemini' is not defined"));
+    }
+
+    #[test]
+    fn test_schema_export() {
+        let schema = config_schema();
+        let audit = &schema["definitions"]["AuditConfig"]["properties"];
+
+        assert_eq!(schema["title"], "OpenSpec configuration");
+        assert!(schema.get("required").is_none());
+        assert_eq!(audit["export_format"]["enum"], json!(["json", "csv", "report"]));
+        assert_eq!(schema["definitions"]["CoverageConfig"]["properties"]["minimum_coverage_percent"]["maximum"], 100.0);
+    }
+}
@@ -0,0 +1,453 @@
+use anyhow::{Context, Result};
+use globset::{GlobSet, GlobSetBuilder};
+use regex::Regex;
+use serde::Deserialize;
+use std::path::Path;
+
+use super::{Diagnostic, Validator};
+use crate::spec::{DeltaKind, Heading, Requirement, Scenario, SpecDocument, Span, StepKeyword};
+use crate::utils::config::{override_glob, RuleSeverity};
+
+/// Workspace-relative path of the team's custom rules
+pub const CUSTOM_RULES_FILE: &str = ".openspec/validation-rules.json";
+
+/// Part of a spec a custom rule inspects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Target {
+    RequirementTitle,
+    RequirementBody,
+    ScenarioName,
+    /// The scenario's steps, one `WHEN ...` line per step
+    ScenarioBody,
+    Heading,
+}
+
+impl Target {
+    fn is_requirement(&self) -> bool {
+        matches!(self, Self::RequirementTitle | Self::RequirementBody)
+    }
+
+    fn is_scenario(&self) -> bool {
+        matches!(self, Self::ScenarioName | Self::ScenarioBody)
+    }
+}
+
+/// Rule as written in `validation-rules.json`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleDefinition {
+    id: String,
+    name: Option<String>,
+    target: Target,
+    /// Regex the target text must match
+    require: Option<String>,
+    /// Regex the target text must not match
+    forbid: Option<String>,
+    min_scenarios: Option<usize>,
+    max_scenarios: Option<usize>,
+    /// Step keywords every scenario must use, e.g. `["WHEN", "THEN"]`
+    #[serde(default)]
+    required_steps: Vec<String>,
+    /// Shown for each violation; `{name}` is replaced by the requirement,
+    /// scenario or heading name
+    message: String,
+    #[serde(default = "default_severity")]
+    severity: RuleSeverity,
+    /// Globs of workspace-relative paths the rule applies to; all files if empty
+    #[serde(default)]
+    files: Vec<String>,
+}
+
+fn default_severity() -> RuleSeverity {
+    RuleSeverity::Warning
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    #[serde(default)]
+    rules: Vec<RuleDefinition>,
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    Require(Regex),
+    Forbid(Regex),
+    MinScenarios(usize),
+    MaxScenarios(usize),
+    RequiredSteps(Vec<StepKeyword>),
+}
+
+/// Compiled custom rule
+#[derive(Debug, Clone)]
+pub struct CustomRule {
+    pub id: String,
+    pub name: String,
+    pub target: Target,
+    pub severity: RuleSeverity,
+    message: String,
+    matchers: Vec<Matcher>,
+    files: Option<GlobSet>,
+}
+
+impl CustomRule {
+    /// Check whether the rule applies to a workspace-relative path
+    pub fn applies_to(&self, file: &str) -> bool {
+        self.files.as_ref().is_none_or(|globs| globs.is_match(file))
+    }
+
+    fn message(&self, name: &str) -> String {
+        self.message.replace("{name}", name)
+    }
+}
+
+/// Rules loaded from `.openspec/validation-rules.json`
+#[derive(Debug, Clone, Default)]
+pub struct CustomRules {
+    pub rules: Vec<CustomRule>,
+}
+
+impl CustomRules {
+    /// Load a workspace's custom rules; a missing file yields no rules
+    pub fn load(workspace_path: &Path) -> Result<Self> {
+        let path = workspace_path.join(CUSTOM_RULES_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", CUSTOM_RULES_FILE))?;
+        Self::parse(&content).with_context(|| format!("Invalid {}", CUSTOM_RULES_FILE))
+    }
+
+    /// Parse and compile a rules document; errors name the offending rule field
+    pub fn parse(json: &str) -> Result<Self> {
+        let file: RulesFile = serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_str(json))
+            .map_err(|e| anyhow::anyhow!("{}: {}", e.path(), e.inner()))?;
+
+        let mut rules: Vec<CustomRule> = Vec::new();
+        for (i, definition) in file.rules.into_iter().enumerate() {
+            let rule = compile(definition).map_err(|e| anyhow::anyhow!("rules[{}].{}", i, e))?;
+            if rules.iter().any(|r| r.id.eq_ignore_ascii_case(&rule.id)) {
+                return Err(anyhow::anyhow!("rules[{}].id: duplicate rule ID '{}'", i, rule.id));
+            }
+            rules.push(rule);
+        }
+        Ok(Self { rules })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+/// Compile one definition; errors are `field: message`
+fn compile(definition: RuleDefinition) -> Result<CustomRule> {
+    let id = definition.id.trim().to_string();
+    if id.is_empty() {
+        return Err(anyhow::anyhow!("id: must not be empty"));
+    }
+    if id.to_ascii_uppercase().starts_with("OS") {
+        return Err(anyhow::anyhow!("id: the OS prefix is reserved for built-in rules"));
+    }
+
+    let regex = |field: &str, pattern: &str| {
+        Regex::new(pattern).map_err(|e| anyhow::anyhow!("{}: invalid regex: {}", field, e))
+    };
+    let mut matchers = Vec::new();
+    if let Some(pattern) = &definition.require {
+        matchers.push(Matcher::Require(regex("require", pattern)?));
+    }
+    if let Some(pattern) = &definition.forbid {
+        matchers.push(Matcher::Forbid(regex("forbid", pattern)?));
+    }
+
+    let requirement_only = |field: &str| {
+        if definition.target.is_requirement() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("{}: only applies to requirement_title or requirement_body targets", field))
+        }
+    };
+    if let Some(min) = definition.min_scenarios {
+        requirement_only("min_scenarios")?;
+        matchers.push(Matcher::MinScenarios(min));
+    }
+    if let Some(max) = definition.max_scenarios {
+        requirement_only("max_scenarios")?;
+        matchers.push(Matcher::MaxScenarios(max));
+    }
+
+    if !definition.required_steps.is_empty() {
+        if !definition.target.is_scenario() {
+            return Err(anyhow::anyhow!(
+                "required_steps: only applies to scenario_name or scenario_body targets"
+            ));
+        }
+        let steps = definition
+            .required_steps
+            .iter()
+            .map(|s| {
+                StepKeyword::from_keyword(s)
+                    .ok_or_else(|| anyhow::anyhow!("required_steps: unknown step keyword '{}'", s))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        matchers.push(Matcher::RequiredSteps(steps));
+    }
+
+    if matchers.is_empty() {
+        return Err(anyhow::anyhow!(
+            "target: no matcher; set require, forbid, min_scenarios, max_scenarios or required_steps"
+        ));
+    }
+
+    let files = if definition.files.is_empty() {
+        None
+    } else {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &definition.files {
+            builder.add(override_glob(pattern).map_err(|e| anyhow::anyhow!("files: {}", e))?);
+        }
+        Some(builder.build()?)
+    };
+
+    Ok(CustomRule {
+        name: definition.name.unwrap_or_else(|| id.to_ascii_lowercase()),
+        id,
+        target: definition.target,
+        severity: definition.severity,
+        message: definition.message,
+        matchers,
+        files,
+    })
+}
+
+/// Run every custom rule that applies to `file`
+pub(super) fn check_custom(
+    validator: &Validator,
+    out: &mut Vec<Diagnostic>,
+    file: &str,
+    doc: &SpecDocument,
+    rules: &CustomRules,
+) {
+    for rule in rules.rules.iter().filter(|r| r.applies_to(file)) {
+        let Some(severity) = validator.custom_severity(rule) else {
+            continue;
+        };
+        let mut emit = |name: &str, span: Span| {
+            out.push(Diagnostic {
+                rule_id: rule.id.clone(),
+                rule_name: rule.name.clone(),
+                severity,
+                message: rule.message(name),
+                file: file.to_string(),
+                span,
+            });
+        };
+
+        match rule.target {
+            Target::RequirementTitle | Target::RequirementBody => {
+                // Removed requirements are on their way out; don't hold them to house rules
+                for requirement in doc.all_requirements().filter(|r| r.delta != Some(DeltaKind::Removed)) {
+                    if let Some(span) = check_requirement(rule, requirement) {
+                        emit(&requirement.name, span);
+                    }
+                }
+            }
+            Target::ScenarioName | Target::ScenarioBody => {
+                let scenarios = doc
+                    .all_requirements()
+                    .flat_map(|r| r.scenarios.iter())
+                    .chain(doc.orphan_scenarios.iter());
+                for scenario in scenarios {
+                    if let Some(span) = check_scenario(rule, scenario) {
+                        emit(&scenario.name, span);
+                    }
+                }
+            }
+            Target::Heading => {
+                for heading in &doc.headings {
+                    if let Some(span) = check_heading(rule, heading) {
+                        emit(&heading.text, span);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Span of the first violation in a requirement, if any
+fn check_requirement(rule: &CustomRule, requirement: &Requirement) -> Option<Span> {
+    let (text, span) = match (rule.target, &requirement.body) {
+        (Target::RequirementBody, Some(body)) => (body.text.as_str(), body.span),
+        (Target::RequirementBody, None) => ("", requirement.heading.span),
+        _ => (requirement.name.as_str(), requirement.name_span),
+    };
+
+    rule.matchers.iter().find_map(|matcher| match matcher {
+        Matcher::Require(re) => (!re.is_match(text)).then_some(span),
+        Matcher::Forbid(re) => re.find(text).map(|m| match (rule.target, &requirement.body) {
+            (Target::RequirementBody, Some(body)) => body.sub_span(m.start(), m.end()),
+            _ => span,
+        }),
+        Matcher::MinScenarios(min) => (requirement.scenarios.len() < *min).then_some(requirement.heading.span),
+        Matcher::MaxScenarios(max) => (requirement.scenarios.len() > *max).then_some(requirement.heading.span),
+        Matcher::RequiredSteps(_) => None,
+    })
+}
+
+/// Span of the first violation in a scenario, if any
+fn check_scenario(rule: &CustomRule, scenario: &Scenario) -> Option<Span> {
+    let body = || {
+        scenario
+            .steps
+            .iter()
+            .map(|s| format!("{} {}", s.keyword.as_str(), s.text))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    rule.matchers.iter().find_map(|matcher| match (matcher, rule.target) {
+        (Matcher::Require(re), Target::ScenarioBody) => (!re.is_match(&body())).then_some(scenario.heading.span),
+        (Matcher::Require(re), _) => (!re.is_match(&scenario.name)).then_some(scenario.name_span),
+        // Point at the offending step rather than the whole scenario
+        (Matcher::Forbid(re), Target::ScenarioBody) => {
+            scenario.steps.iter().find(|s| re.is_match(&s.text)).map(|s| s.span)
+        }
+        (Matcher::Forbid(re), _) => re.is_match(&scenario.name).then_some(scenario.name_span),
+        (Matcher::RequiredSteps(steps), _) => steps
+            .iter()
+            .any(|keyword| !scenario.steps.iter().any(|s| s.keyword == *keyword))
+            .then_some(scenario.heading.span),
+        _ => None,
+    })
+}
+
+fn check_heading(rule: &CustomRule, heading: &Heading) -> Option<Span> {
+    rule.matchers.iter().find_map(|matcher| match matcher {
+        Matcher::Require(re) => (!re.is_match(&heading.text)).then_some(heading.text_span),
+        Matcher::Forbid(re) => re.is_match(&heading.text).then_some(heading.text_span),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::parse_spec;
+    use crate::utils::config::ExtensionConfig;
+    use crate::validation::Severity;
+
+    const RULES: &str = r#"{
+  "rules": [
+    {
+      "id": "TEAM001",
+      "name": "requirement-ticket",
+      "target": "requirement_title",
+      "require": "\\b[A-Z]+-\\d+\\b",
+      "message": "Requirement '{name}' must reference a ticket ID",
+      "severity": "error",
+      "files": ["openspec/specs/**"]
+    },
+    {
+      "id": "TEAM002",
+      "target": "scenario_body",
+      "forbid": "(?i)\\btodo\\b",
+      "required_steps": ["when", "then"],
+      "message": "Scenario '{name}' is unfinished"
+    },
+    {
+      "id": "TEAM003",
+      "target": "requirement_body",
+      "min_scenarios": 2,
+      "message": "Requirement '{name}' needs a failure scenario",
+      "severity": "hint"
+    }
+  ]
+}"#;
+
+    const SPEC: &str = "# Auth
+
+### Requirement: Login (AUTH-12)
+The system SHALL log users in.
+
+#### Scenario: Success
+- **WHEN** credentials are valid
+- **THEN** TODO decide on the token
+
+### Requirement: Logout
+The system SHALL log users out.
+
+#### Scenario: Session ends
+- **WHEN** the user logs out
+";
+
+    #[test]
+    fn test_custom_rules_run_alongside_builtins() {
+        let custom = CustomRules::parse(RULES).unwrap();
+        let rules = ExtensionConfig::default().validation.rules;
+        let diagnostics = Validator::new(&rules)
+            .with_custom_rules(&custom)
+            .validate("openspec/specs/auth/spec.md", &parse_spec(SPEC));
+
+        let found: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.rule_id.as_str(), d.span.line_number(), d.severity))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("TEAM003", 3, Severity::Hint),
+                ("TEAM002", 8, Severity::Warning),
+                ("TEAM003", 10, Severity::Hint),
+                ("TEAM001", 10, Severity::Error),
+                ("TEAM002", 13, Severity::Warning),
+            ]
+        );
+        assert_eq!(diagnostics[3].message, "Requirement 'Logout' must reference a ticket ID");
+        assert_eq!(diagnostics[3].code(), "TEAM001 requirement-ticket");
+        assert_eq!(diagnostics[1].code(), "TEAM002 team002");
+
+        // File globs and config overrides apply to custom rules too
+        let diagnostics = Validator::new(&rules)
+            .with_custom_rules(&custom)
+            .validate("openspec/changes/x/specs/auth/spec.md", &parse_spec(SPEC));
+        assert!(diagnostics.iter().all(|d| d.rule_id != "TEAM001"));
+
+        let mut rules = rules.clone();
+        rules.severity.insert("team002".to_string(), RuleSeverity::Off);
+        let diagnostics = Validator::new(&rules)
+            .with_custom_rules(&custom)
+            .validate("openspec/specs/auth/spec.md", &parse_spec(SPEC));
+        assert!(diagnostics.iter().all(|d| d.rule_id != "TEAM002"));
+
+        rules.severity.insert("requirement-ticket".to_string(), RuleSeverity::Off);
+        rules.severity.insert("TEAM001".to_string(), RuleSeverity::Hint);
+        assert_eq!(Validator::new(&rules).custom_severity(&custom.rules[0]), Some(Severity::Hint));
+    }
+
+    #[test]
+    fn test_file_globs_keep_star_within_a_directory() {
+        let json = r#"{"rules": [{"id": "T1", "target": "heading", "forbid": "x", "message": "m",
+                       "files": ["openspec/specs/*.md"]}]}"#;
+        let rule = &CustomRules::parse(json).unwrap().rules[0];
+        assert!(rule.applies_to("openspec/specs/overview.md"));
+        assert!(!rule.applies_to("openspec/specs/auth/spec.md"));
+    }
+
+    #[test]
+    fn test_invalid_rules_name_the_field() {
+        let error = |json: &str| CustomRules::parse(json).unwrap_err().to_string();
+
+        assert!(error(r#"{"rules": [{"id": "T1", "target": "heading", "require": "(", "message": "m"}]}"#)
+            .starts_with("rules[0].require: invalid regex"));
+        assert!(error(r#"{"rules": [{"id": "T1", "target": "heading", "min_scenarios": 1, "message": "m"}]}"#)
+            .starts_with("rules[0].min_scenarios:"));
+        assert!(error(r#"{"rules": [{"id": "OS100", "target": "heading", "forbid": "x", "message": "m"}]}"#)
+            .contains("reserved"));
+        assert!(error(r#"{"rules": [{"id": "T1", "target": "title", "forbid": "x", "message": "m"}]}"#)
+            .starts_with("rules[0].target:"));
+        assert!(error(r#"{"rules": [{"id": "T1", "target": "heading", "message": "m"}]}"#)
+            .contains("no matcher"));
+    }
+}
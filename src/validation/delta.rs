@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+
+use super::{rules, Diagnostic, Validator};
+use crate::spec::paths::normalize_name;
+use crate::spec::{DeltaKind, SpecDocument};
+
+/// Check a change spec's delta sections against its source spec
+///
+/// `source` is the parsed `openspec/specs/<capability>/spec.md`, or `None`
+/// when the capability has no source spec yet (a brand new capability).
+/// Renames are applied before MODIFIED/REMOVED/ADDED are checked, matching
+/// the order in which `openspec archive` applies them.
+pub(super) fn check_deltas(
+    validator: &Validator,
+    out: &mut Vec<Diagnostic>,
+    file: &str,
+    doc: &SpecDocument,
+    source: Option<&SpecDocument>,
+    source_path: &str,
+) {
+    let mut existing: HashSet<String> = source
+        .map(|s| s.requirements.iter().map(|r| normalize_name(&r.name)).collect())
+        .unwrap_or_default();
+
+    for delta in doc.deltas.iter().filter(|d| d.kind == DeltaKind::Renamed) {
+        for rename in &delta.renames {
+            let (from, to) = match (&rename.from, &rename.to) {
+                (Some(from), Some(to)) if !from.name.is_empty() && !to.name.is_empty() => (from, to),
+                (_, None) => {
+                    validator.emit(
+                        out,
+                        &rules::INVALID_RENAME,
+                        file,
+                        rename.span,
+                        "RENAMED entry is missing a TO line".to_string(),
+                    );
+                    continue;
+                }
+                _ => {
+                    validator.emit(
+                        out,
+                        &rules::INVALID_RENAME,
+                        file,
+                        rename.span,
+                        "RENAMED entry is missing a FROM line or requirement name".to_string(),
+                    );
+                    continue;
+                }
+            };
+
+            let from_name = normalize_name(&from.name);
+            let to_name = normalize_name(&to.name);
+            if !existing.remove(&from_name) {
+                validator.emit(
+                    out,
+                    &rules::DELTA_TARGET_MISSING,
+                    file,
+                    from.span,
+                    missing_message(DeltaKind::Renamed, &from.name, source.is_some(), source_path),
+                );
+            }
+            if !existing.insert(to_name) {
+                validator.emit(
+                    out,
+                    &rules::DELTA_TARGET_EXISTS,
+                    file,
+                    to.span,
+                    format!(
+                        "RENAMED target '{}' already exists in {}",
+                        to.name, source_path
+                    ),
+                );
+            }
+        }
+    }
+
+    for delta in &doc.deltas {
+        for requirement in &delta.requirements {
+            let exists = existing.contains(&normalize_name(&requirement.name));
+            match delta.kind {
+                DeltaKind::Modified | DeltaKind::Removed if !exists => validator.emit(
+                    out,
+                    &rules::DELTA_TARGET_MISSING,
+                    file,
+                    requirement.name_span,
+                    missing_message(delta.kind, &requirement.name, source.is_some(), source_path),
+                ),
+                DeltaKind::Added if exists => validator.emit(
+                    out,
+                    &rules::DELTA_TARGET_EXISTS,
+                    file,
+                    requirement.name_span,
+                    format!(
+                        "ADDED requirement '{}' already exists in {}; use MODIFIED instead",
+                        requirement.name, source_path
+                    ),
+                ),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn missing_message(kind: DeltaKind, name: &str, has_source: bool, source_path: &str) -> String {
+    if has_source {
+        format!(
+            "{} requirement '{}' does not exist in {}",
+            kind.as_str(),
+            name,
+            source_path
+        )
+    } else {
+        format!(
+            "{} requirement '{}' has no source spec: {} does not exist",
+            kind.as_str(),
+            name,
+            source_path
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::spec::parse_spec;
+    use crate::utils::config::ExtensionConfig;
+    use crate::validation::Validator;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const SOURCE: &str = "# Auth
+## Requirements
+### Requirement: Login
+The system SHALL log users in.
+
+#### Scenario: Success
+- **WHEN** credentials are valid
+- **THEN** a token is issued
+
+### Requirement: Logout
+The system SHALL log users out.
+
+#### Scenario: Success
+- **WHEN** the user logs out
+- **THEN** the session ends
+";
+
+    const DELTA: &str = "## ADDED Requirements
+### Requirement: Logout
+The system SHALL log users out.
+
+#### Scenario: Success
+- **WHEN** the user logs out
+- **THEN** the session ends
+
+## MODIFIED Requirements
+### Requirement: User Login
+The system SHALL log users in with 2FA.
+
+#### Scenario: Success
+- **WHEN** credentials and code are valid
+- **THEN** a token is issued
+
+### Requirement: Password Reset
+The system SHALL reset passwords.
+
+#### Scenario: Reset
+- **WHEN** a reset is requested
+- **THEN** an email is sent
+
+## REMOVED Requirements
+### Requirement: Session Tokens
+
+## RENAMED Requirements
+- FROM: `### Requirement: Login`
+- TO: `### Requirement: User Login`
+- FROM: `### Requirement: Signup`
+";
+
+    fn validate(source: Option<&str>) -> Vec<String> {
+        let temp_dir = TempDir::new().unwrap();
+        if let Some(source) = source {
+            let dir = temp_dir.path().join("openspec/specs/auth");
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("spec.md"), source).unwrap();
+        }
+
+        let rules = ExtensionConfig::default().validation.rules;
+        Validator::new(&rules)
+            .with_workspace(temp_dir.path())
+            .validate("openspec/changes/add-2fa/specs/auth/spec.md", &parse_spec(DELTA))
+            .into_iter()
+            .map(|d| format!("{} {}", d.rule_id, d.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_delta_semantics_against_source() {
+        let findings = validate(Some(SOURCE));
+
+        assert_eq!(
+            findings,
+            vec![
+                "OS010 ADDED requirement 'Logout' already exists in openspec/specs/auth/spec.md; use MODIFIED instead",
+                "OS009 MODIFIED requirement 'Password Reset' does not exist in openspec/specs/auth/spec.md",
+                "OS009 REMOVED requirement 'Session Tokens' does not exist in openspec/specs/auth/spec.md",
+                "OS011 RENAMED entry is missing a TO line",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_delta_without_source_spec() {
+        let findings = validate(None);
+
+        assert!(findings.iter().any(|f| f.starts_with("OS009 MODIFIED requirement 'Password Reset' has no source spec")));
+        assert!(findings.iter().any(|f| f.starts_with("OS009 RENAMED requirement 'Login' has no source spec")));
+        assert!(!findings.iter().any(|f| f.starts_with("OS010")));
+    }
+}
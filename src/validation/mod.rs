@@ -0,0 +1,558 @@
+pub mod custom;
+mod delta;
+pub mod normative;
+pub mod rules;
+pub mod sarif;
+pub mod size;
+pub mod suppress;
+mod tasks;
+
+use serde::Serialize;
+use std::path::{Component, Path};
+
+use crate::spec::paths::{self, SpecLocation};
+use crate::spec::tasks::{parse_tasks, TaskList};
+use crate::spec::{parse_spec, DeltaKind, Requirement, SpecDocument, Span};
+use crate::utils::config::{RuleSeverity, ValidationRules};
+use custom::{CustomRule, CustomRules};
+use rules::Rule;
+use size::{ChangeSizeReport, SizeLevel};
+
+/// Severity of a validation finding, mirroring LSP diagnostic severities
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Information,
+    Hint,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Information => "info",
+            Self::Hint => "hint",
+        }
+    }
+}
+
+/// A single validation finding anchored to a location in a spec file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diagnostic {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub severity: Severity,
+    pub message: String,
+    /// Workspace-relative path of the validated file
+    pub file: String,
+    pub span: Span,
+}
+
+impl Diagnostic {
+    /// Rule code as shown to users, e.g. `OS001 missing-scenario`
+    pub fn code(&self) -> String {
+        format!("{} {}", self.rule_id, self.rule_name)
+    }
+}
+
+/// Count diagnostics of a given severity
+pub fn count_severity(diagnostics: &[Diagnostic], severity: Severity) -> usize {
+    diagnostics.iter().filter(|d| d.severity == severity).count()
+}
+
+/// Check whether a workspace-relative path is a change's `tasks.md`
+pub fn is_tasks_file(file: &str) -> bool {
+    file.rsplit('/').next() == Some("tasks.md")
+}
+
+/// Runs the built-in rules, plus any custom rules, against parsed spec files
+pub struct Validator<'a> {
+    rules: &'a ValidationRules,
+    workspace: Option<&'a Path>,
+    custom: Option<&'a CustomRules>,
+}
+
+impl<'a> Validator<'a> {
+    pub fn new(rules: &'a ValidationRules) -> Self {
+        Self {
+            rules,
+            workspace: None,
+            custom: None,
+        }
+    }
+
+    /// Resolve change specs against source specs in this workspace
+    ///
+    /// Without a workspace, delta sections are only checked structurally.
+    pub fn with_workspace(mut self, workspace: &'a Path) -> Self {
+        self.workspace = Some(workspace);
+        self
+    }
+
+    /// Also run the team's rules from `.openspec/validation-rules.json`
+    pub fn with_custom_rules(mut self, custom: &'a CustomRules) -> Self {
+        self.custom = Some(custom);
+        self
+    }
+
+    /// Effective severity of a rule after config toggles and overrides,
+    /// or `None` when the rule is turned off
+    pub fn severity_for(&self, rule: &Rule) -> Option<Severity> {
+        if rule == &rules::MISSING_SCENARIO && !self.rules.require_scenarios {
+            return None;
+        }
+        let normative = [rules::MISSING_SHALL_MUST, rules::WEAK_NORMATIVE_LANGUAGE];
+        if normative.contains(rule) && !self.rules.require_shall_must {
+            return None;
+        }
+
+        match self.severity_override(rule.id, rule.name) {
+            None => Some(rule.default_severity),
+            Some(level) => to_severity(level),
+        }
+    }
+
+    /// Effective severity of a custom rule; config overrides win over the
+    /// severity in the rules file
+    pub fn custom_severity(&self, rule: &CustomRule) -> Option<Severity> {
+        let level = self.severity_override(&rule.id, &rule.name);
+        to_severity(level.unwrap_or(rule.severity))
+    }
+
+    /// Configured level for a rule; a key naming the rule's ID wins over one
+    /// naming the rule, so configs setting both resolve the same way every time
+    fn severity_override(&self, id: &str, name: &str) -> Option<RuleSeverity> {
+        let severity = &self.rules.severity;
+        severity
+            .get(id)
+            .or_else(|| {
+                severity
+                    .iter()
+                    .filter(|(key, _)| id.eq_ignore_ascii_case(key))
+                    .min_by_key(|(key, _)| *key)
+                    .map(|(_, level)| level)
+            })
+            .or_else(|| severity.get(name))
+            .copied()
+    }
+
+    /// Validate a parsed spec; `file` is the workspace-relative path
+    pub fn validate(&self, file: &str, doc: &SpecDocument) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+
+        let in_openspec = Path::new(file)
+            .components()
+            .any(|c| matches!(c, Component::Normal(name) if name == "openspec"));
+        if !in_openspec {
+            let span = doc.title.as_ref().map(|t| t.span).unwrap_or_default();
+            self.emit(
+                &mut out,
+                &rules::OUTSIDE_OPENSPEC_DIR,
+                file,
+                span,
+                "File is not in openspec/ directory".to_string(),
+            );
+        }
+
+        // The document spans the whole source, so its end is the size in bytes
+        let bytes = doc.span.end as u64;
+        let title_span = doc.title.as_ref().map(|t| t.span).unwrap_or_default();
+        match size::size_level(bytes, self.rules) {
+            SizeLevel::Error => self.emit(
+                &mut out,
+                &rules::SPEC_TOO_LARGE,
+                file,
+                title_span,
+                format!(
+                    "Spec is {}, exceeding the {} KB limit",
+                    size::format_kb(bytes),
+                    self.rules.max_spec_size_kb
+                ),
+            ),
+            SizeLevel::Warning => self.emit(
+                &mut out,
+                &rules::LARGE_SPEC,
+                file,
+                title_span,
+                format!(
+                    "Spec is {}, above the {} KB soft limit",
+                    size::format_kb(bytes),
+                    self.rules.warn_spec_size_kb
+                ),
+            ),
+            SizeLevel::Ok => {}
+        }
+
+        if doc.requirement_count() == 0 && !doc.is_delta() {
+            let span = doc.title.as_ref().map(|t| t.span).unwrap_or_default();
+            self.emit(
+                &mut out,
+                &rules::NO_REQUIREMENTS,
+                file,
+                span,
+                "No requirements or spec deltas found".to_string(),
+            );
+        }
+
+        for heading in &doc.headings {
+            if let Some(expected) = heading.expected_level().filter(|&level| level != heading.level) {
+                self.emit(
+                    &mut out,
+                    &rules::HEADING_LEVEL,
+                    file,
+                    heading.span,
+                    format!(
+                        "Heading '{}' should use {} (level {}), not {}",
+                        heading.text,
+                        "#".repeat(expected),
+                        expected,
+                        "#".repeat(heading.level)
+                    ),
+                );
+            }
+        }
+
+        // Change specs only take effect through delta sections
+        if matches!(paths::classify(file), SpecLocation::Delta { .. }) {
+            for requirement in &doc.requirements {
+                self.emit(
+                    &mut out,
+                    &rules::REQUIREMENT_OUTSIDE_DELTA,
+                    file,
+                    requirement.heading.span,
+                    format!(
+                        "Requirement '{}' is not in an ADDED, MODIFIED, REMOVED or RENAMED section",
+                        requirement.name
+                    ),
+                );
+            }
+        }
+
+        for requirement in doc.all_requirements() {
+            // Removed requirements only carry a reason, not scenarios or a normative statement
+            if requirement.delta == Some(DeltaKind::Removed) {
+                continue;
+            }
+
+            if requirement.scenarios.is_empty() {
+                self.emit(
+                    &mut out,
+                    &rules::MISSING_SCENARIO,
+                    file,
+                    requirement.heading.span,
+                    format!("Requirement '{}' has no scenarios", requirement.name),
+                );
+            }
+
+            self.check_normative_language(&mut out, file, requirement);
+        }
+
+        for scenario in &doc.orphan_scenarios {
+            self.emit(
+                &mut out,
+                &rules::ORPHAN_SCENARIO,
+                file,
+                scenario.heading.span,
+                format!("Scenario '{}' is not under a requirement", scenario.name),
+            );
+        }
+
+        if let (Some(workspace), SpecLocation::Delta { capability, .. }) =
+            (self.workspace, paths::classify(file))
+        {
+            let source_path = paths::source_spec_path(capability);
+            let source = std::fs::read_to_string(workspace.join(&source_path))
+                .ok()
+                .map(|text| parse_spec(&text));
+            delta::check_deltas(self, &mut out, file, doc, source.as_ref(), &source_path);
+        }
+
+        if let Some(custom) = self.custom {
+            custom::check_custom(self, &mut out, file, doc, custom);
+        }
+
+        out.sort_by_key(|d| d.span.start);
+        out
+    }
+
+    /// Parse and validate a file, choosing task or spec rules by file name
+    ///
+    /// Honors `openspec-disable` comments in the source.
+    pub fn validate_source(&self, file: &str, text: &str) -> Vec<Diagnostic> {
+        let diagnostics = if is_tasks_file(file) {
+            self.validate_tasks(file, &parse_tasks(text))
+        } else {
+            self.validate(file, &parse_spec(text))
+        };
+        self.apply_suppressions(file, text, diagnostics)
+    }
+
+    /// Drop findings silenced by `openspec-disable` comments in `text`, and
+    /// report comments that no longer silence anything
+    pub fn apply_suppressions(&self, file: &str, text: &str, diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+        suppress::apply_suppressions(self, file, text, diagnostics)
+    }
+
+    /// Check whether a rule, built-in or custom, is currently reported
+    ///
+    /// Unknown keys count as enabled.
+    pub fn is_rule_enabled(&self, key: &str) -> bool {
+        if let Some(rule) = rules::find_rule(key) {
+            return self.severity_for(rule).is_some();
+        }
+        let custom = self
+            .custom
+            .and_then(|c| c.rules.iter().find(|r| r.id.eq_ignore_ascii_case(key) || r.name == key));
+        match custom {
+            Some(rule) => self.custom_severity(rule).is_some(),
+            None => true,
+        }
+    }
+
+    /// Validate a parsed `tasks.md`; `file` is the workspace-relative path
+    pub fn validate_tasks(&self, file: &str, list: &TaskList) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+        tasks::check_tasks(self, &mut out, file, list);
+        out.sort_by_key(|d| d.span.start);
+        out
+    }
+
+    /// Check a change's total size against the change limits, anchoring the
+    /// finding at the start of `file`
+    pub fn validate_change_size(&self, file: &str, report: &ChangeSizeReport) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+        match report.total_level {
+            SizeLevel::Error => self.emit(
+                &mut out,
+                &rules::CHANGE_TOO_LARGE,
+                file,
+                Span::default(),
+                format!(
+                    "Change '{}' is {} across {} file(s), exceeding the {} KB limit",
+                    report.change_id,
+                    size::format_kb(report.total_bytes),
+                    report.files.len(),
+                    report.max_kb
+                ),
+            ),
+            SizeLevel::Warning => self.emit(
+                &mut out,
+                &rules::CHANGE_LARGE,
+                file,
+                Span::default(),
+                format!(
+                    "Change '{}' is {} across {} file(s), above the {} KB soft limit",
+                    report.change_id,
+                    size::format_kb(report.total_bytes),
+                    report.files.len(),
+                    report.warn_kb
+                ),
+            ),
+            SizeLevel::Ok => {}
+        }
+        out
+    }
+
+    /// Flag bodies without SHALL/MUST and sentences relying on weak modal verbs
+    fn check_normative_language(&self, out: &mut Vec<Diagnostic>, file: &str, requirement: &Requirement) {
+        let Some(body) = &requirement.body else {
+            self.emit(
+                out,
+                &rules::MISSING_SHALL_MUST,
+                file,
+                requirement.heading.span,
+                format!("Requirement '{}' has no normative statement", requirement.name),
+            );
+            return;
+        };
+
+        let sentences = normative::sentences(body);
+        if !sentences.iter().any(|s| s.is_normative()) {
+            // The first sentence is the requirement's normative statement
+            let span = sentences.first().map(|s| s.span).unwrap_or(body.span);
+            self.emit(
+                out,
+                &rules::MISSING_SHALL_MUST,
+                file,
+                span,
+                format!("Requirement '{}' does not use SHALL or MUST", requirement.name),
+            );
+        }
+
+        for sentence in sentences.iter().filter(|s| !s.is_normative()) {
+            if let Some(keyword) = sentence.weak_keyword() {
+                self.emit(
+                    out,
+                    &rules::WEAK_NORMATIVE_LANGUAGE,
+                    file,
+                    sentence.span,
+                    format!("Weak wording '{}'; use SHALL or MUST for normative statements", keyword),
+                );
+            }
+        }
+    }
+
+    fn emit(&self, out: &mut Vec<Diagnostic>, rule: &Rule, file: &str, span: Span, message: String) {
+        if let Some(severity) = self.severity_for(rule) {
+            out.push(Diagnostic {
+                rule_id: rule.id.to_string(),
+                rule_name: rule.name.to_string(),
+                severity,
+                message,
+                file: file.to_string(),
+                span,
+            });
+        }
+    }
+}
+
+fn to_severity(level: RuleSeverity) -> Option<Severity> {
+    match level {
+        RuleSeverity::Off => None,
+        RuleSeverity::Error => Some(Severity::Error),
+        RuleSeverity::Warning => Some(Severity::Warning),
+        RuleSeverity::Info => Some(Severity::Information),
+        RuleSeverity::Hint => Some(Severity::Hint),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::parse_spec;
+    use crate::utils::config::ExtensionConfig;
+
+    const SPEC: &str = "# Auth
+
+### Requirement: Login
+The system SHALL log users in.
+
+### Requirement: Logout
+The system SHALL log users out.
+
+#### Scenario: Session ends
+- **WHEN** the user logs out
+- **THEN** the session is revoked
+";
+
+    #[test]
+    fn test_findings_carry_rule_and_location() {
+        let rules = ExtensionConfig::default().validation.rules;
+        let diagnostics = Validator::new(&rules).validate("openspec/specs/auth/spec.md", &parse_spec(SPEC));
+
+        assert_eq!(diagnostics.len(), 1);
+        let finding = &diagnostics[0];
+        assert_eq!(finding.code(), "OS001 missing-scenario");
+        assert_eq!(finding.severity, Severity::Warning);
+        assert_eq!(finding.file, "openspec/specs/auth/spec.md");
+        assert_eq!(finding.span.line_number(), 3);
+    }
+
+    #[test]
+    fn test_severity_overrides_and_toggles() {
+        let mut rules = ExtensionConfig::default().validation.rules;
+        let doc = parse_spec(SPEC);
+
+        rules.severity.insert("missing-scenario".to_string(), RuleSeverity::Error);
+        let diagnostics = Validator::new(&rules).validate("openspec/specs/auth/spec.md", &doc);
+        assert_eq!(count_severity(&diagnostics, Severity::Error), 1);
+
+        // The ID wins when both the ID and the name are set
+        rules.severity.insert("OS001".to_string(), RuleSeverity::Off);
+        assert!(Validator::new(&rules).validate("openspec/specs/auth/spec.md", &doc).is_empty());
+        rules.severity.insert("os001".to_string(), RuleSeverity::Off);
+        rules.severity.remove("OS001");
+        assert!(Validator::new(&rules).validate("openspec/specs/auth/spec.md", &doc).is_empty());
+
+        rules.severity.clear();
+        rules.require_scenarios = false;
+        assert!(Validator::new(&rules).validate("openspec/specs/auth/spec.md", &doc).is_empty());
+    }
+
+    #[test]
+    fn test_normative_language_rules() {
+        let rules = ExtensionConfig::default().validation.rules;
+        let source = "### Requirement: Export
+The exporter should write CSV. Output is compressed.
+
+#### Scenario: Export
+- **WHEN** an export runs
+- **THEN** a file is written
+
+## REMOVED Requirements
+### Requirement: Legacy Export
+**Reason**: replaced by Export
+";
+        let diagnostics = Validator::new(&rules).validate("openspec/specs/export/spec.md", &parse_spec(source));
+        let codes: Vec<_> = diagnostics.iter().map(|d| d.rule_id.as_str()).collect();
+        assert_eq!(codes, vec!["OS005", "OS006"]);
+
+        let missing = &diagnostics[0];
+        assert_eq!(missing.severity, Severity::Error);
+        assert_eq!(&source[missing.span.start..missing.span.end], "The exporter should write CSV.");
+        assert_eq!(diagnostics[1].severity, Severity::Hint);
+        assert!(diagnostics[1].message.contains("'should'"));
+
+        let mut relaxed = rules.clone();
+        relaxed.require_shall_must = false;
+        let diagnostics = Validator::new(&relaxed).validate("openspec/specs/export/spec.md", &parse_spec(source));
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_spec_size_limits() {
+        let mut rules = ExtensionConfig::default().validation.rules;
+        rules.warn_spec_size_kb = 1;
+        rules.max_spec_size_kb = 2;
+        let padding = "The system SHALL be padded.\n".repeat(50);
+
+        let source = format!("{}{}", SPEC, padding);
+        let diagnostics = Validator::new(&rules).validate("openspec/specs/auth/spec.md", &parse_spec(&source));
+        assert_eq!(diagnostics[0].code(), "OS007 large-spec");
+        assert_eq!(diagnostics[0].span.line_number(), 1);
+
+        let source = format!("{}{}{}", SPEC, padding, padding);
+        let diagnostics = Validator::new(&rules).validate("openspec/specs/auth/spec.md", &parse_spec(&source));
+        assert_eq!(diagnostics[0].code(), "OS008 spec-too-large");
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_heading_levels_and_requirements_outside_deltas() {
+        let rules = ExtensionConfig::default().validation.rules;
+        let source = "### Requirement: Export
+The system SHALL export CSV.
+
+### Scenario: Export
+- **WHEN** an export runs
+- **THEN** a file is written
+
+# ADDED Requirements
+### Requirement: Import
+The system SHALL import CSV.
+
+#### Scenario: Import
+- **WHEN** an import runs
+- **THEN** rows are created
+";
+        let file = "openspec/changes/add-csv/specs/export/spec.md";
+        let diagnostics = Validator::new(&rules).validate(file, &parse_spec(source));
+        let found: Vec<_> = diagnostics.iter().map(|d| (d.rule_id.as_str(), d.span.line_number())).collect();
+        assert_eq!(found, vec![("OS016", 1), ("OS015", 4), ("OS015", 8)]);
+        assert_eq!(diagnostics[1].message, "Heading 'Scenario: Export' should use #### (level 4), not ###");
+
+        // Source specs have no delta sections to be outside of
+        let diagnostics = Validator::new(&rules).validate("openspec/specs/export/spec.md", &parse_spec(source));
+        assert!(diagnostics.iter().all(|d| d.rule_id == "OS015"));
+    }
+
+    #[test]
+    fn test_rule_lookup_by_id_or_name() {
+        assert_eq!(rules::find_rule("OS002"), Some(&rules::ORPHAN_SCENARIO));
+        assert_eq!(rules::find_rule("no-requirements"), Some(&rules::NO_REQUIREMENTS));
+        assert!(rules::find_rule("OS999").is_none());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::spec::{Span, TextBlock};
+
+/// Keywords that make a statement normative in OpenSpec
+const NORMATIVE_KEYWORDS: &[&str] = &["SHALL", "MUST"];
+
+/// Modal verbs that express intent without an obligation
+const WEAK_KEYWORDS: &[&str] = &["should", "may", "could"];
+
+/// Sentence within a requirement body
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sentence {
+    pub text: String,
+    pub span: Span,
+}
+
+impl Sentence {
+    /// Check whether the sentence contains an uppercase SHALL or MUST
+    pub fn is_normative(&self) -> bool {
+        words(&self.text).any(|w| NORMATIVE_KEYWORDS.contains(&w))
+    }
+
+    /// First weak modal verb (should, may, could) used in the sentence
+    pub fn weak_keyword(&self) -> Option<&str> {
+        words(&self.text).find(|w| WEAK_KEYWORDS.iter().any(|k| k.eq_ignore_ascii_case(w)))
+    }
+}
+
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty())
+}
+
+/// Split a requirement body into sentences
+///
+/// Sentences end at `.`, `!` or `?` followed by whitespace, at blank lines,
+/// and before list items, so each bullet is checked on its own.
+pub fn sentences(block: &TextBlock) -> Vec<Sentence> {
+    let text = &block.text;
+    let mut sentences = Vec::new();
+    let mut start = 0;
+
+    let mut push = |start: usize, end: usize| {
+        let raw = &text[start..end];
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            return;
+        }
+        let offset = start + (raw.len() - raw.trim_start().len());
+        sentences.push(Sentence {
+            text: trimmed.to_string(),
+            span: block.sub_span(offset, offset + trimmed.len()),
+        });
+    };
+
+    let bytes = text.as_bytes();
+    for (i, &byte) in bytes.iter().enumerate() {
+        let next = bytes.get(i + 1).copied();
+        match byte {
+            b'.' | b'!' | b'?' if next.is_none_or(|b| b.is_ascii_whitespace()) => {
+                push(start, i + 1);
+                start = i + 1;
+            }
+            b'\n' => {
+                let rest = text[i + 1..].trim_start_matches([' ', '\t']);
+                if rest.starts_with('\n') || rest.starts_with("- ") || rest.starts_with("* ") {
+                    push(start, i);
+                    start = i + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    push(start, text.len());
+
+    sentences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::parse_spec;
+
+    #[test]
+    fn test_sentence_spans_and_keywords() {
+        let source = "### Requirement: Export\nUsers may export data. The system SHALL\nwrite CSV files.\n\n- It should compress output\n";
+        let doc = parse_spec(source);
+        let body = doc.requirements[0].body.as_ref().unwrap();
+        let sentences = sentences(body);
+
+        assert_eq!(sentences.len(), 3);
+        assert_eq!(sentences[0].text, "Users may export data.");
+        assert_eq!(sentences[0].weak_keyword(), Some("may"));
+        assert!(!sentences[0].is_normative());
+
+        assert!(sentences[1].is_normative());
+        assert_eq!(&source[sentences[1].span.start..sentences[1].span.end], "The system SHALL\nwrite CSV files.");
+        assert_eq!(sentences[1].span.end_pos.line, 2);
+
+        assert_eq!(sentences[2].text, "- It should compress output");
+        assert_eq!(sentences[2].span.start_pos.line, 4);
+        assert_eq!(sentences[2].span.start_pos.column, 0);
+    }
+
+    #[test]
+    fn test_lowercase_shall_is_not_normative() {
+        let doc = parse_spec("### Requirement: X\nThe system shall respond.\n");
+        let body = doc.requirements[0].body.as_ref().unwrap();
+        assert!(!sentences(body)[0].is_normative());
+    }
+}
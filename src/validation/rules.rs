@@ -0,0 +1,191 @@
+use super::Severity;
+
+/// Built-in validation rule with a stable ID and kebab-case name
+///
+/// IDs never change once released, so they are safe to use in severity
+/// overrides, suppressions and CI filters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub default_severity: Severity,
+    pub description: &'static str,
+}
+
+impl Rule {
+    /// Check whether `key` refers to this rule by ID or name
+    pub fn matches(&self, key: &str) -> bool {
+        self.id.eq_ignore_ascii_case(key) || self.name == key
+    }
+}
+
+/// Requirement without any `#### Scenario:` block (`rules.require_scenarios`)
+pub const MISSING_SCENARIO: Rule = Rule {
+    id: "OS001",
+    name: "missing-scenario",
+    default_severity: Severity::Warning,
+    description: "Every requirement must have at least one scenario",
+};
+
+/// Scenario appearing before any requirement heading
+pub const ORPHAN_SCENARIO: Rule = Rule {
+    id: "OS002",
+    name: "orphan-scenario",
+    default_severity: Severity::Warning,
+    description: "Scenarios must be nested under a requirement",
+};
+
+/// Spec without requirements or delta sections
+pub const NO_REQUIREMENTS: Rule = Rule {
+    id: "OS003",
+    name: "no-requirements",
+    default_severity: Severity::Warning,
+    description: "Spec files must define requirements or spec deltas",
+};
+
+/// Spec file outside the `openspec/` directory
+pub const OUTSIDE_OPENSPEC_DIR: Rule = Rule {
+    id: "OS004",
+    name: "outside-openspec-dir",
+    default_severity: Severity::Warning,
+    description: "Spec files must live under the openspec/ directory",
+};
+
+/// Requirement whose body has no SHALL/MUST statement (`rules.require_shall_must`)
+pub const MISSING_SHALL_MUST: Rule = Rule {
+    id: "OS005",
+    name: "missing-shall-must",
+    default_severity: Severity::Error,
+    description: "Requirements must state normative behaviour with SHALL or MUST",
+};
+
+/// Sentence using should/may/could instead of SHALL/MUST (`rules.require_shall_must`)
+pub const WEAK_NORMATIVE_LANGUAGE: Rule = Rule {
+    id: "OS006",
+    name: "weak-normative-language",
+    default_severity: Severity::Hint,
+    description: "Prefer SHALL or MUST over should, may or could in requirements",
+};
+
+/// Spec larger than `rules.warn_spec_size_kb`
+pub const LARGE_SPEC: Rule = Rule {
+    id: "OS007",
+    name: "large-spec",
+    default_severity: Severity::Warning,
+    description: "Spec files should stay below the soft size limit",
+};
+
+/// Spec larger than `rules.max_spec_size_kb`
+pub const SPEC_TOO_LARGE: Rule = Rule {
+    id: "OS008",
+    name: "spec-too-large",
+    default_severity: Severity::Error,
+    description: "Spec files must not exceed the hard size limit",
+};
+
+/// MODIFIED/REMOVED/RENAMED requirement missing from the source spec
+pub const DELTA_TARGET_MISSING: Rule = Rule {
+    id: "OS009",
+    name: "delta-target-missing",
+    default_severity: Severity::Error,
+    description: "MODIFIED, REMOVED and RENAMED requirements must exist in the source spec",
+};
+
+/// ADDED requirement or RENAMED target already present in the source spec
+pub const DELTA_TARGET_EXISTS: Rule = Rule {
+    id: "OS010",
+    name: "delta-target-exists",
+    default_severity: Severity::Error,
+    description: "ADDED requirements and RENAMED targets must not already exist in the source spec",
+};
+
+/// RENAMED entry without a complete FROM/TO pair
+pub const INVALID_RENAME: Rule = Rule {
+    id: "OS011",
+    name: "invalid-rename",
+    default_severity: Severity::Error,
+    description: "RENAMED entries must pair a FROM line with a TO line",
+};
+
+/// Task ID used more than once in `tasks.md`
+pub const DUPLICATE_TASK_ID: Rule = Rule {
+    id: "OS012",
+    name: "duplicate-task-id",
+    default_severity: Severity::Error,
+    description: "Task IDs must be unique within tasks.md",
+};
+
+/// Task ID not in `X.Y` form, or not matching its section or parent task
+pub const MALFORMED_TASK_ID: Rule = Rule {
+    id: "OS013",
+    name: "malformed-task-id",
+    default_severity: Severity::Warning,
+    description: "Task IDs must use the X.Y form and extend their section and parent numbers",
+};
+
+/// `openspec-disable` comment that no longer silences any finding
+pub const UNUSED_SUPPRESSION: Rule = Rule {
+    id: "OS014",
+    name: "unused-suppression",
+    default_severity: Severity::Warning,
+    description: "Suppression comments must silence at least one finding; remove stale ones",
+};
+
+/// Delta section, requirement or scenario heading at the wrong level
+pub const HEADING_LEVEL: Rule = Rule {
+    id: "OS015",
+    name: "heading-level",
+    default_severity: Severity::Warning,
+    description: "Delta sections use ##, requirements ### and scenarios #### headings",
+};
+
+/// Requirement in a change spec that is not inside a delta section
+pub const REQUIREMENT_OUTSIDE_DELTA: Rule = Rule {
+    id: "OS016",
+    name: "requirement-outside-delta",
+    default_severity: Severity::Error,
+    description: "Requirements in change specs must be under an ADDED, MODIFIED, REMOVED or RENAMED section",
+};
+
+/// Change whose markdown files together exceed `rules.warn_change_size_kb`
+pub const CHANGE_LARGE: Rule = Rule {
+    id: "OS017",
+    name: "change-large",
+    default_severity: Severity::Warning,
+    description: "Changes should stay below the soft size limit across all their files",
+};
+
+/// Change whose markdown files together exceed `rules.max_change_size_kb`
+pub const CHANGE_TOO_LARGE: Rule = Rule {
+    id: "OS018",
+    name: "change-too-large",
+    default_severity: Severity::Error,
+    description: "Changes must not exceed the hard size limit across all their files",
+};
+
+/// Every built-in rule, in ID order
+pub const ALL_RULES: &[Rule] = &[
+    MISSING_SCENARIO,
+    ORPHAN_SCENARIO,
+    NO_REQUIREMENTS,
+    OUTSIDE_OPENSPEC_DIR,
+    MISSING_SHALL_MUST,
+    WEAK_NORMATIVE_LANGUAGE,
+    LARGE_SPEC,
+    SPEC_TOO_LARGE,
+    DELTA_TARGET_MISSING,
+    DELTA_TARGET_EXISTS,
+    INVALID_RENAME,
+    DUPLICATE_TASK_ID,
+    MALFORMED_TASK_ID,
+    UNUSED_SUPPRESSION,
+    HEADING_LEVEL,
+    REQUIREMENT_OUTSIDE_DELTA,
+    CHANGE_LARGE,
+    CHANGE_TOO_LARGE,
+];
+
+/// Look up a built-in rule by ID (`OS001`) or name (`missing-scenario`)
+pub fn find_rule(key: &str) -> Option<&'static Rule> {
+    ALL_RULES.iter().find(|rule| rule.matches(key))
+}
@@ -0,0 +1,148 @@
+//! SARIF 2.1.0 export of validation findings, for code-scanning tools
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+use super::rules::{Rule, ALL_RULES};
+use super::{Diagnostic, Severity};
+
+/// SARIF schema referenced from exported logs
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Key of the fingerprint in each result's `partialFingerprints`
+const FINGERPRINT_KEY: &str = "openspecFindingHash/v1";
+
+/// Build a SARIF log with a single run covering `diagnostics`
+///
+/// Every built-in rule is listed in the tool metadata; custom rules are
+/// listed when they have findings.
+pub fn sarif_log(diagnostics: &[&Diagnostic]) -> Value {
+    let mut rules: Vec<Value> = ALL_RULES.iter().map(rule_descriptor).collect();
+    let mut rule_index: HashMap<&str, usize> =
+        ALL_RULES.iter().enumerate().map(|(i, rule)| (rule.id, i)).collect();
+    for diagnostic in diagnostics {
+        if !rule_index.contains_key(diagnostic.rule_id.as_str()) {
+            rule_index.insert(&diagnostic.rule_id, rules.len());
+            rules.push(json!({
+                "id": diagnostic.rule_id,
+                "name": diagnostic.rule_name,
+            }));
+        }
+    }
+
+    let mut seen: HashMap<u64, usize> = HashMap::new();
+    let results: Vec<Value> = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let hash = fingerprint(diagnostic);
+            let occurrence = seen.entry(hash).or_default();
+            *occurrence += 1;
+            json!({
+                "ruleId": diagnostic.rule_id,
+                "ruleIndex": rule_index[diagnostic.rule_id.as_str()],
+                "level": level(diagnostic.severity),
+                "message": { "text": diagnostic.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": diagnostic.file, "uriBaseId": "%SRCROOT%" },
+                        "region": {
+                            "startLine": diagnostic.span.start_pos.line + 1,
+                            "startColumn": diagnostic.span.start_pos.column + 1,
+                            "endLine": diagnostic.span.end_pos.line + 1,
+                            "endColumn": diagnostic.span.end_pos.column + 1,
+                        },
+                    },
+                }],
+                "partialFingerprints": {
+                    FINGERPRINT_KEY: format!("{:016x}:{}", hash, occurrence),
+                },
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "openspec",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/Fission-AI/OpenSpec",
+                    "rules": rules,
+                },
+            },
+            // Span columns count Unicode scalar values
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }],
+    })
+}
+
+fn rule_descriptor(rule: &Rule) -> Value {
+    json!({
+        "id": rule.id,
+        "name": rule.name,
+        "shortDescription": { "text": rule.description },
+        "defaultConfiguration": { "level": level(rule.default_severity) },
+    })
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Information | Severity::Hint => "note",
+    }
+}
+
+/// Stable hash of a finding that ignores its line, so it survives edits
+/// elsewhere in the file; repeated findings are told apart by occurrence
+fn fingerprint(diagnostic: &Diagnostic) -> u64 {
+    // FNV-1a, which unlike `DefaultHasher` is stable across Rust releases
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in [&diagnostic.rule_id, &diagnostic.file, &diagnostic.message] {
+        for byte in part.bytes().chain([0]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::config::ExtensionConfig;
+    use crate::validation::Validator;
+
+    #[test]
+    fn test_sarif_log() {
+        let rules = ExtensionConfig::default().validation.rules;
+        let source = "# Auth\n\n### Requirement: Logout\nThe system SHALL log users out.\n";
+        let diagnostics = Validator::new(&rules).validate_source("openspec/specs/auth/spec.md", source);
+        let log = sarif_log(&diagnostics.iter().collect::<Vec<_>>());
+
+        let run = &log["runs"][0];
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), ALL_RULES.len());
+        assert_eq!(run["tool"]["driver"]["rules"][0]["defaultConfiguration"]["level"], "warning");
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "OS001");
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "warning");
+        let region = &result["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region["startLine"], 3);
+        assert_eq!(region["startColumn"], 1);
+
+        // Moving the finding to another line keeps its fingerprint
+        let moved = format!("\n\n{}", source);
+        let diagnostics = Validator::new(&rules).validate_source("openspec/specs/auth/spec.md", &moved);
+        let moved_log = sarif_log(&diagnostics.iter().collect::<Vec<_>>());
+        assert_eq!(
+            moved_log["runs"][0]["results"][0]["partialFingerprints"],
+            result["partialFingerprints"]
+        );
+    }
+}
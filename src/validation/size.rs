@@ -0,0 +1,172 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::utils::config::ValidationRules;
+use crate::utils::fs;
+
+/// Where a size falls relative to the configured limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SizeLevel {
+    Ok,
+    /// Above the soft limit
+    Warning,
+    /// Above the hard limit
+    Error,
+}
+
+/// Level of a single spec against `warn_spec_size_kb` / `max_spec_size_kb`
+pub fn size_level(bytes: u64, rules: &ValidationRules) -> SizeLevel {
+    level(bytes, rules.warn_spec_size_kb, rules.max_spec_size_kb)
+}
+
+/// Level of a change total against `warn_change_size_kb` / `max_change_size_kb`
+pub fn change_size_level(bytes: u64, rules: &ValidationRules) -> SizeLevel {
+    level(bytes, rules.warn_change_size_kb, rules.max_change_size_kb)
+}
+
+fn level(bytes: u64, warn_kb: usize, max_kb: usize) -> SizeLevel {
+    if bytes > max_kb as u64 * 1024 {
+        SizeLevel::Error
+    } else if bytes > warn_kb as u64 * 1024 {
+        SizeLevel::Warning
+    } else {
+        SizeLevel::Ok
+    }
+}
+
+/// Format a byte count as kilobytes, e.g. `12.5 KB`
+pub fn format_kb(bytes: u64) -> String {
+    format!("{:.1} KB", bytes as f64 / 1024.0)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileSize {
+    /// Path relative to the change directory
+    pub path: String,
+    pub bytes: u64,
+    pub level: SizeLevel,
+}
+
+/// Size of every markdown file in a change directory, with per-file and
+/// whole-change limit checks
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangeSizeReport {
+    pub change_id: String,
+    pub files: Vec<FileSize>,
+    pub total_bytes: u64,
+    /// `total_bytes` against the change limits
+    pub total_level: SizeLevel,
+    /// Change limits in KB, for reporting
+    pub warn_kb: usize,
+    pub max_kb: usize,
+}
+
+impl ChangeSizeReport {
+    pub fn collect(workspace_path: &Path, change_id: &str, rules: &ValidationRules) -> Result<Self> {
+        let change_dir = workspace_path.join("openspec").join("changes").join(change_id);
+        let mut files = Vec::new();
+
+        for path in fs::list_files_recursive(&change_dir)? {
+            if path.extension().is_none_or(|ext| ext != "md") {
+                continue;
+            }
+            let bytes = fs::file_size(&path)?;
+            let relative = path.strip_prefix(&change_dir).unwrap_or(&path);
+            files.push(FileSize {
+                path: relative.to_string_lossy().replace('\\', "/"),
+                bytes,
+                level: size_level(bytes, rules),
+            });
+        }
+
+        let total_bytes = files.iter().map(|f| f.bytes).sum();
+        Ok(Self {
+            change_id: change_id.to_string(),
+            total_level: change_size_level(total_bytes, rules),
+            total_bytes,
+            files,
+            warn_kb: rules.warn_change_size_kb,
+            max_kb: rules.max_change_size_kb,
+        })
+    }
+
+    pub fn count(&self, level: SizeLevel) -> usize {
+        self.files.iter().filter(|f| f.level == level).count()
+    }
+
+    /// Summary, e.g. `Change 'x': 40.0 KB across 3 file(s), 1 over soft limit, 0 over hard limit`
+    ///
+    /// The total against the change limits is reported as OS017/OS018 findings.
+    pub fn summary(&self) -> String {
+        format!(
+            "Change '{}': {} across {} file(s), {} over soft limit, {} over hard limit",
+            self.change_id,
+            format_kb(self.total_bytes),
+            self.files.len(),
+            self.count(SizeLevel::Warning),
+            self.count(SizeLevel::Error)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::config::ExtensionConfig;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_change_size_report() {
+        let mut rules = ExtensionConfig::default().validation.rules;
+        rules.warn_spec_size_kb = 1;
+        rules.max_spec_size_kb = 2;
+
+        let temp_dir = TempDir::new().unwrap();
+        let change_dir = temp_dir.path().join("openspec/changes/add-export");
+        fs::create_dir_all(&change_dir.join("specs/export")).unwrap();
+        fs::write_file(&change_dir.join("proposal.md"), "small").unwrap();
+        fs::write_file(&change_dir.join("specs/export/spec.md"), &"x".repeat(1500)).unwrap();
+        fs::write_file(&change_dir.join("tasks.md"), &"x".repeat(3000)).unwrap();
+        fs::write_file(&change_dir.join("diagram.png"), &"x".repeat(9000)).unwrap();
+
+        let report = ChangeSizeReport::collect(temp_dir.path(), "add-export", &rules).unwrap();
+
+        assert_eq!(report.files.len(), 3);
+        assert_eq!(report.total_bytes, 4505);
+        assert_eq!(report.files[1].path, "specs/export/spec.md");
+        assert_eq!(report.files[1].level, SizeLevel::Warning);
+        assert_eq!(report.files[2].level, SizeLevel::Error);
+        assert_eq!(report.total_level, SizeLevel::Ok);
+        assert_eq!(
+            report.summary(),
+            "Change 'add-export': 4.4 KB across 3 file(s), 1 over soft limit, 1 over hard limit"
+        );
+    }
+
+    #[test]
+    fn test_change_total_limits() {
+        let mut rules = ExtensionConfig::default().validation.rules;
+        rules.warn_spec_size_kb = 2;
+        rules.max_spec_size_kb = 4;
+        rules.warn_change_size_kb = 3;
+        rules.max_change_size_kb = 5;
+
+        let temp_dir = TempDir::new().unwrap();
+        let change_dir = temp_dir.path().join("openspec/changes/add-export");
+        fs::create_dir_all(&change_dir).unwrap();
+        // Every file is under the per-file soft limit
+        for name in ["proposal.md", "design.md"] {
+            fs::write_file(&change_dir.join(name), &"x".repeat(1800)).unwrap();
+        }
+
+        let report = ChangeSizeReport::collect(temp_dir.path(), "add-export", &rules).unwrap();
+        assert_eq!(report.count(SizeLevel::Warning), 0);
+        assert_eq!(report.total_level, SizeLevel::Warning);
+
+        fs::write_file(&change_dir.join("tasks.md"), &"x".repeat(1800)).unwrap();
+        let report = ChangeSizeReport::collect(temp_dir.path(), "add-export", &rules).unwrap();
+        assert_eq!(report.total_level, SizeLevel::Error);
+    }
+}
@@ -0,0 +1,291 @@
+use super::{rules, Diagnostic, Validator};
+use crate::spec::{LineIndex, Span};
+
+/// Scope of an `openspec-disable` comment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuppressionScope {
+    /// `<!-- openspec-disable OS010 -->`: the whole file
+    File,
+    /// `<!-- openspec-disable-next-line OS003 -->`: the next non-blank line
+    NextLine {
+        /// Zero-based line the suppression applies to
+        line: usize,
+    },
+}
+
+/// Parsed suppression comment
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suppression {
+    pub scope: SuppressionScope,
+    /// Rule IDs or names; empty means every rule
+    pub rules: Vec<String>,
+    /// The comment itself
+    pub span: Span,
+}
+
+impl Suppression {
+    /// Index of the listed rule that covers a finding (0 for a bare
+    /// suppression), or `None` if the finding is not covered
+    fn matching_rule(&self, diagnostic: &Diagnostic) -> Option<usize> {
+        if let SuppressionScope::NextLine { line } = self.scope {
+            if diagnostic.span.start_pos.line != line {
+                return None;
+            }
+        }
+        if self.rules.is_empty() {
+            return Some(0);
+        }
+        self.rules.iter().position(|key| {
+            diagnostic.rule_id.eq_ignore_ascii_case(key) || diagnostic.rule_name == *key
+        })
+    }
+}
+
+/// Find `openspec-disable` comments outside code fences
+///
+/// A `-- reason` after the rule list is allowed and ignored.
+pub fn parse_suppressions(source: &str) -> Vec<Suppression> {
+    let index = LineIndex::new(source);
+    let lines: Vec<&str> = source.lines().collect();
+    let mut suppressions = Vec::new();
+    let mut in_fence = false;
+    let mut offset = 0;
+
+    for (number, raw) in source.split_inclusive('\n').enumerate() {
+        let start = offset;
+        offset += raw.len();
+
+        let line = raw.trim_end_matches(['\n', '\r']);
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        let Some(comment_start) = line.find("<!--") else {
+            continue;
+        };
+        let body_start = comment_start + 4;
+        let Some(body_len) = line[body_start..].find("-->") else {
+            continue;
+        };
+        let body = line[body_start..body_start + body_len].trim();
+
+        let (scope, list) = if let Some(list) = body.strip_prefix("openspec-disable-next-line") {
+            let target = (number + 1..lines.len()).find(|&n| !lines[n].trim().is_empty());
+            let Some(line) = target else {
+                continue;
+            };
+            (SuppressionScope::NextLine { line }, list)
+        } else if let Some(list) = body.strip_prefix("openspec-disable") {
+            (SuppressionScope::File, list)
+        } else {
+            continue;
+        };
+        // Require a separator so `openspec-disabled` isn't read as a suppression
+        if !list.is_empty() && !list.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        let list = list.split(" -- ").next().unwrap_or("");
+        suppressions.push(Suppression {
+            scope,
+            rules: list
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|key| !key.is_empty())
+                .map(str::to_string)
+                .collect(),
+            span: index.span(start + comment_start, start + body_start + body_len + 3),
+        });
+    }
+
+    suppressions
+}
+
+/// Drop suppressed findings and report suppressions that matched nothing
+pub(super) fn apply_suppressions(
+    validator: &Validator,
+    file: &str,
+    source: &str,
+    diagnostics: Vec<Diagnostic>,
+) -> Vec<Diagnostic> {
+    let suppressions = parse_suppressions(source);
+    if suppressions.is_empty() {
+        return diagnostics;
+    }
+
+    // Per suppression: whether each listed rule (or the whole comment) was used
+    let mut used: Vec<Vec<bool>> = suppressions
+        .iter()
+        .map(|s| vec![false; s.rules.len().max(1)])
+        .collect();
+
+    let mut out = Vec::new();
+    for diagnostic in diagnostics {
+        if !silence(&suppressions, &mut used, None, &diagnostic) {
+            out.push(diagnostic);
+        }
+    }
+
+    // Stale comments are findings too, so other comments can silence them.
+    // Suppressions of OS014 itself only become stale once those have run.
+    let mut stale = Vec::new();
+    let mut stale_os014 = Vec::new();
+    for (owner, (suppression, used)) in suppressions.iter().zip(&used).enumerate() {
+        if suppression.rules.is_empty() {
+            if !used[0] {
+                stale.push((owner, 0, "Suppression comment does not silence any finding".to_string()));
+            }
+            continue;
+        }
+        for (entry, (key, used)) in suppression.rules.iter().zip(used).enumerate() {
+            // A rule that is currently turned off may still need its suppression elsewhere
+            if *used || !validator.is_rule_enabled(key) {
+                continue;
+            }
+            let message = format!("Suppression for '{}' does not silence any finding", key);
+            if rules::UNUSED_SUPPRESSION.matches(key) {
+                stale_os014.push((owner, entry, message));
+            } else {
+                stale.push((owner, entry, message));
+            }
+        }
+    }
+
+    let mut reports = Vec::new();
+    for (owner, entry, message) in stale {
+        let mut report = Vec::new();
+        validator.emit(&mut report, &rules::UNUSED_SUPPRESSION, file, suppressions[owner].span, message);
+        for diagnostic in report {
+            if !silence(&suppressions, &mut used, Some(owner), &diagnostic) {
+                reports.push((owner, entry, diagnostic));
+            }
+        }
+    }
+    // A bare comment counts as used once it silenced another comment's report
+    out.extend(
+        reports
+            .into_iter()
+            .filter(|(owner, entry, _)| !used[*owner][*entry])
+            .map(|(_, _, diagnostic)| diagnostic),
+    );
+    for (owner, entry, message) in stale_os014 {
+        if !used[owner][entry] {
+            validator.emit(&mut out, &rules::UNUSED_SUPPRESSION, file, suppressions[owner].span, message);
+        }
+    }
+
+    out.sort_by_key(|d| d.span.start);
+    out
+}
+
+/// Mark every suppression covering `diagnostic` as used, except the one at
+/// `skip`, and report whether any did
+fn silence(
+    suppressions: &[Suppression],
+    used: &mut [Vec<bool>],
+    skip: Option<usize>,
+    diagnostic: &Diagnostic,
+) -> bool {
+    let mut suppressed = false;
+    for (index, (suppression, used)) in suppressions.iter().zip(used.iter_mut()).enumerate() {
+        if Some(index) == skip {
+            continue;
+        }
+        if let Some(entry) = suppression.matching_rule(diagnostic) {
+            used[entry] = true;
+            suppressed = true;
+        }
+    }
+    suppressed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::config::{ExtensionConfig, RuleSeverity};
+
+    const SPEC: &str = "# Notes
+<!-- openspec-disable OS003 -- design notes, no requirements yet -->
+<!-- openspec-disable-next-line OS001, OS005 -->
+
+### Requirement: Draft
+The system SHALL do something.
+
+```markdown
+<!-- openspec-disable OS002 -->
+```
+";
+
+    #[test]
+    fn test_parse_suppressions() {
+        let suppressions = parse_suppressions(SPEC);
+
+        assert_eq!(suppressions.len(), 2);
+        assert_eq!(suppressions[0].scope, SuppressionScope::File);
+        assert_eq!(suppressions[0].rules, vec!["OS003"]);
+        assert_eq!(suppressions[1].scope, SuppressionScope::NextLine { line: 4 });
+        assert_eq!(suppressions[1].rules, vec!["OS001", "OS005"]);
+        assert_eq!(suppressions[1].span.line_number(), 3);
+
+        assert!(parse_suppressions("<!-- openspec-disabled OS001 -->\n").is_empty());
+        let all = parse_suppressions("<!-- openspec-disable -->\n");
+        assert!(all[0].rules.is_empty());
+
+        // The closing `-->` must not overlap the opening `<!--`
+        assert!(parse_suppressions("<!-->\n<!--->\n").is_empty());
+        let tight = parse_suppressions("<!--openspec-disable OS001-->\n");
+        assert_eq!(tight[0].rules, vec!["OS001"]);
+        assert_eq!(tight[0].span.end, 29);
+    }
+
+    #[test]
+    fn test_suppressed_and_unused_findings() {
+        let rules = ExtensionConfig::default().validation.rules;
+        let source = SPEC.replace("### Requirement: Draft\nThe system SHALL do something.\n", "## Draft\n");
+        let diagnostics = Validator::new(&rules).validate_source("openspec/specs/notes/spec.md", &source);
+
+        // OS003 is silenced file-wide; the next-line comment now targets a plain heading
+        let found: Vec<_> = diagnostics.iter().map(|d| (d.rule_id.as_str(), d.message.as_str())).collect();
+        assert_eq!(
+            found,
+            vec![
+                ("OS014", "Suppression for 'OS001' does not silence any finding"),
+                ("OS014", "Suppression for 'OS005' does not silence any finding"),
+            ]
+        );
+
+        // OS001 is silenced on the requirement heading; OS003 and OS005 never fire
+        let diagnostics = Validator::new(&rules).validate_source("openspec/specs/notes/spec.md", SPEC);
+        let found: Vec<_> = diagnostics.iter().map(|d| (d.rule_id.as_str(), d.span.line_number())).collect();
+        assert_eq!(found, vec![("OS014", 2), ("OS014", 3)]);
+        assert!(diagnostics[1].message.contains("'OS005'"));
+
+        // Suppressions for rules that are turned off are not reported
+        let mut relaxed = rules.clone();
+        relaxed.severity.insert("OS005".to_string(), RuleSeverity::Off);
+        relaxed.severity.insert("missing-scenario".to_string(), RuleSeverity::Off);
+        assert!(Validator::new(&relaxed).validate_source("openspec/specs/notes/spec.md", &source).is_empty());
+
+        // OS014 findings go through the same filter, so they can be silenced too
+        let kept = source.replace(
+            "<!-- openspec-disable-next-line OS001",
+            "<!-- openspec-disable-next-line OS014 -- kept for the migration -->\n<!-- openspec-disable-next-line OS001",
+        );
+        assert!(Validator::new(&rules).validate_source("openspec/specs/notes/spec.md", &kept).is_empty());
+
+        let silenced = format!("<!-- openspec-disable unused-suppression -->\n{}", source);
+        assert!(Validator::new(&rules).validate_source("openspec/specs/notes/spec.md", &silenced).is_empty());
+
+        // An OS014 suppression that silences nothing is reported itself
+        let stale = SPEC
+            .replace("OS001, OS005", "OS001")
+            .replace("OS003 -- design notes, no requirements yet", "OS014");
+        let diagnostics = Validator::new(&rules).validate_source("openspec/specs/notes/spec.md", &stale);
+        let found: Vec<_> = diagnostics.iter().map(|d| (d.rule_id.as_str(), d.span.line_number())).collect();
+        assert_eq!(found, vec![("OS014", 2)]);
+    }
+}
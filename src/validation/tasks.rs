@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use super::{rules, Diagnostic, Validator};
+use crate::spec::tasks::{is_well_formed_id, Task, TaskList};
+
+/// Check that task IDs are unique, use the `X.Y` form, and match their
+/// section number and parent task
+pub(super) fn check_tasks(validator: &Validator, out: &mut Vec<Diagnostic>, file: &str, list: &TaskList) {
+    let mut seen = HashMap::new();
+    for section in &list.sections {
+        check_ids(validator, out, file, section.number.as_deref(), None, &section.tasks, &mut seen);
+    }
+}
+
+fn check_ids<'a>(
+    validator: &Validator,
+    out: &mut Vec<Diagnostic>,
+    file: &str,
+    section: Option<&str>,
+    parent: Option<&str>,
+    tasks: &'a [Task],
+    seen: &mut HashMap<&'a str, usize>,
+) {
+    for task in tasks {
+        let (Some(id), Some(span)) = (task.id.as_deref(), task.id_span) else {
+            check_ids(validator, out, file, section, None, &task.children, seen);
+            continue;
+        };
+
+        let problem = if !is_well_formed_id(id) {
+            Some(format!("Task ID '{}' is not in X.Y form", id))
+        } else if let Some(parent) = parent {
+            (!id.starts_with(&format!("{}.", parent)))
+                .then(|| format!("Subtask ID '{}' does not extend parent task '{}'", id, parent))
+        } else if let Some(section) = section {
+            (!id.starts_with(&format!("{}.", section)))
+                .then(|| format!("Task ID '{}' does not match section {}", id, section))
+        } else {
+            None
+        };
+        if let Some(message) = problem {
+            validator.emit(out, &rules::MALFORMED_TASK_ID, file, span, message);
+        }
+
+        if let Some(line) = seen.insert(id, span.line_number()) {
+            validator.emit(
+                out,
+                &rules::DUPLICATE_TASK_ID,
+                file,
+                span,
+                format!("Task ID '{}' is already used on line {}", id, line),
+            );
+        }
+
+        let parent = is_well_formed_id(id).then_some(id);
+        check_ids(validator, out, file, section, parent, &task.children, seen);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::spec::tasks::parse_tasks;
+    use crate::utils::config::ExtensionConfig;
+    use crate::validation::Validator;
+
+    #[test]
+    fn test_task_id_rules() {
+        let source = "## 1. Build
+- [ ] 1.1 Parser
+- [ ] 2.2 Wrong section
+  - [ ] 1.3.1 Wrong parent
+- [ ] 1.1 Duplicate
+- [ ] 4 Single number
+- [ ] No ID at all
+";
+        let rules = ExtensionConfig::default().validation.rules;
+        let findings: Vec<_> = Validator::new(&rules)
+            .validate_tasks("openspec/changes/x/tasks.md", &parse_tasks(source))
+            .into_iter()
+            .map(|d| format!("{}:{} {}", d.span.line_number(), d.rule_id, d.message))
+            .collect();
+
+        assert_eq!(
+            findings,
+            vec![
+                "3:OS013 Task ID '2.2' does not match section 1",
+                "4:OS013 Subtask ID '1.3.1' does not extend parent task '2.2'",
+                "5:OS012 Task ID '1.1' is already used on line 2",
+                "6:OS013 Task ID '4' is not in X.Y form",
+            ]
+        );
+    }
+}
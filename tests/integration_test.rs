@@ -1,14 +1,16 @@
-use std::path::PathBuf;
-
 #[cfg(test)]
 mod integration_tests {
-    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+    use zed_openspec::commands::output::CommandOutput;
+    use zed_openspec::commands::validate::handle_validate_file;
+    use zed_openspec::commands::CommandHandler;
+    use zed_openspec::utils::config::{ExtensionConfig, RuleSeverity};
 
     /// Test that extension compiles and basic structure is correct
     #[test]
     fn test_extension_builds() {
         // This test will pass if the extension compiles successfully
-        assert!(true);
     }
 
     /// Test proposal name validation
@@ -36,7 +38,192 @@ mod integration_tests {
     /// Test configuration defaults
     #[test]
     fn test_config_defaults() {
-        // This will be implemented when config module is complete
-        assert!(true);
+        let config = ExtensionConfig::default();
+
+        assert_eq!(config.llm.default_provider, "claude");
+        assert!(config.llm.providers.contains_key(&config.llm.default_provider));
+        assert!(config.validation.enabled);
+        assert_eq!(config.validation.debounce_ms, 500);
+        assert!(config.validation.rules.require_scenarios);
+    }
+
+    /// Test that validation reports the requirement missing scenarios by line
+    #[test]
+    fn test_validate_file_reports_requirement_location() {
+        let temp_dir = TempDir::new().unwrap();
+        let spec_dir = temp_dir.path().join("openspec/specs/auth");
+        fs::create_dir_all(&spec_dir).unwrap();
+        fs::write(
+            spec_dir.join("spec.md"),
+            "# Auth\n\n### Requirement: Login\nThe system SHALL log users in.\n\n\
+             #### Scenario: Success\n- **WHEN** credentials are valid\n- **THEN** a token is issued\n\n\
+             ### Requirement: Logout\nThe system SHALL log users out.\n",
+        )
+        .unwrap();
+
+        let output = handle_validate_file(
+            temp_dir.path(),
+            "openspec/specs/auth/spec.md",
+            &ExtensionConfig::default(),
+        )
+        .unwrap()
+        .to_text();
+
+        assert!(output.contains(
+            "Line 10:1 warning [OS001 missing-scenario] Requirement 'Logout' has no scenarios"
+        ));
+        assert!(!output.contains("'Login'"));
+        assert!(output.contains("- 2 requirement(s)"));
+        assert!(output.contains("- 1 scenario(s)"));
+        assert!(output.contains("- 0 error(s), 1 warning(s)"));
+    }
+
+    /// Test that validating a change spec reports the change's aggregate size
+    #[test]
+    fn test_validate_change_spec_reports_change_size() {
+        let temp_dir = TempDir::new().unwrap();
+        let change_dir = temp_dir.path().join("openspec/changes/add-export");
+        fs::create_dir_all(change_dir.join("specs/export")).unwrap();
+        fs::write(change_dir.join("proposal.md"), "# Add export
+").unwrap();
+        fs::write(
+            change_dir.join("specs/export/spec.md"),
+            "## ADDED Requirements\n### Requirement: Export\nThe system SHALL export CSV.\n\n\
+             #### Scenario: Export\n- **WHEN** export runs\n- **THEN** a file is written\n",
+        )
+        .unwrap();
+
+        let output = handle_validate_file(
+            temp_dir.path(),
+            "openspec/changes/add-export/specs/export/spec.md",
+            &ExtensionConfig::default(),
+        )
+        .unwrap()
+        .to_text();
+
+        assert!(output.starts_with("✓ Validation passed"));
+        assert!(output.contains("Change 'add-export':"));
+        assert!(output.contains("across 2 file(s), 0 over soft limit, 0 over hard limit"));
+    }
+
+    /// Test that a change over the aggregate size limit fails validation
+    #[test]
+    fn test_validate_change_over_size_limit_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        let change_dir = temp_dir.path().join("openspec/changes/add-export");
+        fs::create_dir_all(change_dir.join("specs/export")).unwrap();
+        fs::write(change_dir.join("design.md"), "x".repeat(2500)).unwrap();
+        fs::write(
+            change_dir.join("specs/export/spec.md"),
+            "## ADDED Requirements\n### Requirement: Export\nThe system SHALL export CSV.\n\n\
+             #### Scenario: Export\n- **WHEN** export runs\n- **THEN** a file is written\n",
+        )
+        .unwrap();
+
+        let mut config = ExtensionConfig::default();
+        config.validation.rules.warn_change_size_kb = 1;
+        config.validation.rules.max_change_size_kb = 2;
+        let file = "openspec/changes/add-export/specs/export/spec.md";
+
+        let result = handle_validate_file(temp_dir.path(), file, &config).unwrap();
+        assert!(!result.passed());
+        assert_eq!(result.report.errors, 1);
+        assert_eq!(result.report.diagnostics[0].code(), "OS018 change-too-large");
+        assert!(result.to_text().contains("exceeding the 2 KB limit"));
+
+        config.validation.rules.max_change_size_kb = 4;
+        let result = handle_validate_file(temp_dir.path(), file, &config).unwrap();
+        assert!(result.passed());
+        assert_eq!(result.report.warnings, 1);
+        assert_eq!(result.report.diagnostics[0].code(), "OS017 change-large");
+
+        config.validation.rules.severity.insert("change-large".to_string(), RuleSeverity::Off);
+        let result = handle_validate_file(temp_dir.path(), file, &config).unwrap();
+        assert!(result.report.diagnostics.is_empty());
+    }
+
+    /// Test that commands render a versioned JSON envelope on request
+    #[test]
+    fn test_json_output() {
+        let temp_dir = TempDir::new().unwrap();
+        let spec_dir = temp_dir.path().join("openspec/specs/auth");
+        fs::create_dir_all(&spec_dir).unwrap();
+        fs::write(spec_dir.join("spec.md"), "# Auth\n\n### Requirement: Logout\nThe system SHALL log users out.\n").unwrap();
+        let handler = CommandHandler::new(ExtensionConfig::default());
+
+        let output = handler
+            .execute(
+                "openspec:validate-file",
+                vec!["openspec/specs/auth/spec.md".to_string(), "--format".to_string(), "json".to_string()],
+                temp_dir.path(),
+            )
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(json["command"], "validate-file");
+        assert_eq!(json["result"]["contents"]["kind"], "spec");
+        assert_eq!(json["result"]["warnings"], 1);
+        assert_eq!(json["result"]["diagnostics"][0]["rule_id"], "OS001");
+        assert_eq!(json["result"]["diagnostics"][0]["span"]["start_pos"]["line"], 2);
+
+        let output = handler
+            .execute("openspec:list-changes", vec!["--json".to_string()], temp_dir.path())
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["result"]["initialized"], false);
+
+        let error = handler
+            .execute("openspec:validate-file", vec!["--json".to_string()], temp_dir.path())
+            .unwrap_err();
+        let json: serde_json::Value = serde_json::from_str(&error).unwrap();
+        assert_eq!(json["command"], "validate-file");
+        assert_eq!(json["error"], "File path required");
+    }
+
+    /// Test that workspace validation fails with the full report when errors exist
+    #[test]
+    fn test_validate_all_fails_on_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let spec_dir = temp_dir.path().join("openspec/specs/auth");
+        fs::create_dir_all(&spec_dir).unwrap();
+        fs::write(spec_dir.join("spec.md"), "# Auth\n\n### Requirement: Logout\nThe system SHALL log users out.\n").unwrap();
+
+        let handler = CommandHandler::new(ExtensionConfig::default());
+        let output = handler.execute("openspec:validate-all", Vec::new(), temp_dir.path()).unwrap();
+        assert!(output.starts_with("⚠ Validation warnings: 0 error(s), 1 warning(s) in 1 file(s)"));
+
+        let mut config = ExtensionConfig::default();
+        config.validation.rules.require_scenarios = false;
+        config.validation.rules.require_shall_must = true;
+        fs::write(spec_dir.join("spec.md"), "# Auth\n\n### Requirement: Logout\nUsers can log out.\n").unwrap();
+        let handler = CommandHandler::new(config);
+        let error = handler
+            .execute("openspec:validate-all", vec!["--json".to_string()], temp_dir.path())
+            .unwrap_err();
+        let json: serde_json::Value = serde_json::from_str(&error).unwrap();
+        assert_eq!(json["command"], "validate-all");
+        assert_eq!(json["result"]["files"][0]["file"], "openspec/specs/auth/spec.md");
+        assert!(json["result"]["errors"].as_u64().unwrap() > 0);
+    }
+
+    /// Test that `junit.include_tasks` controls task test cases
+    #[test]
+    fn test_junit_task_cases_follow_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let change_dir = temp_dir.path().join("openspec/changes/add-2fa");
+        fs::create_dir_all(&change_dir).unwrap();
+        fs::write(change_dir.join("tasks.md"), "## 1. Setup\n- [x] 1.1 Add setting\n- [ ] 1.2 Add UI\n").unwrap();
+        let args = || vec!["--format".to_string(), "junit".to_string()];
+
+        let handler = CommandHandler::new(ExtensionConfig::default());
+        let xml = handler.execute("openspec:validate-all", args(), temp_dir.path()).unwrap();
+        assert!(xml.contains("<testcase name=\"Task: 1.2 Add UI\""));
+
+        let mut config = ExtensionConfig::default();
+        config.junit.include_tasks = false;
+        let handler = CommandHandler::new(config);
+        let xml = handler.execute("openspec:validate-all", args(), temp_dir.path()).unwrap();
+        assert!(!xml.contains("Task: "));
+        assert!(xml.contains("tests=\"1\" failures=\"0\" skipped=\"0\""));
     }
 }